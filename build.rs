@@ -0,0 +1,63 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ *
+ * Generates the solution registry (see `src/registry.rs`) from the `YYYY_DD.rs` files in `src/bin`.
+ * Only the runner binaries include it, through `advent_of_code::solutions!`.
+ */
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn parse_day(file_name: &str) -> Option<(u16, u8)> {
+    let (year, day) = file_name.strip_suffix(".rs")?.split_once('_')?;
+    if year.len() != 4 || day.len() != 2 {
+        return None;
+    }
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+fn main() {
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let bin_dir = manifest_dir.join("src").join("bin");
+    println!("cargo:rerun-if-changed={}", bin_dir.display());
+
+    let mut days: Vec<(u16, u8, PathBuf)> = fs::read_dir(&bin_dir)
+        .expect("could not read src/bin")
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let (year, day) = parse_day(path.file_name()?.to_str()?)?;
+            Some((year, day, path))
+        })
+        .collect();
+    days.sort();
+
+    let mut modules = String::new();
+    let mut entries = String::new();
+    for (year, day, path) in days.iter() {
+        let module = format!("y{}_d{:02}", year, day);
+        writeln!(
+            modules,
+            "#[path = {:?}]\n#[allow(dead_code)]\nmod {};",
+            path.display().to_string(),
+            module
+        )
+        .unwrap();
+        writeln!(
+            entries,
+            "    advent_of_code::registry::Entry {{ year: {}, day: {}, solution: &advent_of_code::registry::Parts(|i| {m}::part_one(i).into_answer(), |i| {m}::part_two(i).into_answer()) }},",
+            year,
+            day,
+            m = module
+        )
+        .unwrap();
+    }
+
+    let out = format!(
+        "// @generated by build.rs\nuse advent_of_code::results::IntoAnswer as _;\n\n{}\npub static SOLUTIONS: &[advent_of_code::registry::Entry] = &[\n{}];\n",
+        modules, entries
+    );
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("solutions.rs");
+    fs::write(out_path, out).expect("could not write solution registry");
+}
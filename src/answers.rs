@@ -0,0 +1,218 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::aoc::SubmitOutcome;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Incorrect,
+}
+
+impl Verdict {
+    /// Submission outcomes that say something about the answer itself.
+    pub fn from_outcome(outcome: &SubmitOutcome) -> Option<Self> {
+        match outcome {
+            SubmitOutcome::Correct => Some(Verdict::Correct),
+            SubmitOutcome::TooHigh => Some(Verdict::TooHigh),
+            SubmitOutcome::TooLow => Some(Verdict::TooLow),
+            SubmitOutcome::Incorrect => Some(Verdict::Incorrect),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Seconds since the unix epoch.
+    pub submitted_at: u64,
+}
+
+/// Answers submitted for a day, stored in `src/answers/<year>_<day>.toml`.
+/// Answers confirmed elsewhere can be added by hand as a submission with `verdict = "correct"`.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Answers {
+    #[serde(default, rename = "submission")]
+    pub submissions: Vec<Submission>,
+}
+
+/// How an answer relates to what was recorded for its part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    /// Nothing that confirms or contradicts the answer was recorded.
+    Unverified,
+    Confirmed,
+    /// A different answer was confirmed as correct.
+    Changed {
+        confirmed: String,
+    },
+    /// The same answer was submitted before and rejected.
+    Rejected(Verdict),
+    /// The answer lies beyond a guess that was rejected as too high or too low.
+    OutOfBounds {
+        guess: String,
+        verdict: Verdict,
+    },
+}
+
+impl Check {
+    pub fn warning(&self) -> Option<String> {
+        match self {
+            Check::Unverified | Check::Confirmed => None,
+            Check::Changed { confirmed } => {
+                Some(format!("differs from confirmed answer {}", confirmed))
+            }
+            Check::Rejected(verdict) => Some(format!(
+                "was already rejected{}",
+                match verdict {
+                    Verdict::TooHigh => " as too high",
+                    Verdict::TooLow => " as too low",
+                    _ => "",
+                }
+            )),
+            Check::OutOfBounds { guess, verdict } => Some(format!(
+                "is {} rejected guess {}",
+                match verdict {
+                    Verdict::TooLow => "not above",
+                    _ => "not below",
+                },
+                guess
+            )),
+        }
+    }
+}
+
+pub fn answers_path(year: u16, day: u8) -> PathBuf {
+    crate::data_dir()
+        .join("answers")
+        .join(format!("{}_{:02}.toml", year, day))
+}
+
+impl Answers {
+    pub fn load(year: u16, day: u8) -> Result<Self> {
+        let path = answers_path(year, day);
+        match fs::read_to_string(&path) {
+            Ok(contents) => {
+                toml::from_str(&contents).with_context(|| format!("could not parse {:?}", path))
+            }
+            Err(_) => Ok(Answers::default()),
+        }
+    }
+
+    pub fn save(&self, year: u16, day: u8) -> Result<()> {
+        crate::write_atomic(&answers_path(year, day), &toml::to_string(self)?)
+    }
+
+    pub fn confirmed(&self, part: u8) -> Option<&str> {
+        self.submissions
+            .iter()
+            .find(|s| s.part == part && s.verdict == Verdict::Correct)
+            .map(|s| s.answer.as_str())
+    }
+
+    pub fn check(&self, part: u8, answer: &str) -> Check {
+        if let Some(confirmed) = self.confirmed(part) {
+            return match confirmed == answer {
+                true => Check::Confirmed,
+                false => Check::Changed {
+                    confirmed: confirmed.to_string(),
+                },
+            };
+        }
+
+        let rejected = self
+            .submissions
+            .iter()
+            .filter(|s| s.part == part && s.verdict != Verdict::Correct);
+
+        if let Some(submission) = rejected.clone().find(|s| s.answer == answer) {
+            return Check::Rejected(submission.verdict);
+        }
+
+        let value = match answer.parse::<i128>() {
+            Ok(value) => value,
+            Err(_) => return Check::Unverified,
+        };
+        rejected
+            .filter(|s| match (s.verdict, s.answer.parse::<i128>()) {
+                (Verdict::TooHigh, Ok(guess)) => value >= guess,
+                (Verdict::TooLow, Ok(guess)) => value <= guess,
+                _ => false,
+            })
+            .map(|s| Check::OutOfBounds {
+                guess: s.answer.clone(),
+                verdict: s.verdict,
+            })
+            .next()
+            .unwrap_or(Check::Unverified)
+    }
+
+    pub fn record(&mut self, part: u8, answer: &str, verdict: Verdict) {
+        self.submissions.push(Submission {
+            part,
+            answer: answer.to_string(),
+            verdict,
+            submitted_at: crate::unix_time(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answers_roundtrip() {
+        let mut answers = Answers::default();
+        answers.record(1, "1500", Verdict::TooLow);
+        answers.record(1, "1651", Verdict::Correct);
+
+        let serialized = toml::to_string(&answers).unwrap();
+        assert!(serialized.contains("[[submission]]"));
+        assert!(serialized.contains("verdict = \"too_low\""));
+        assert_eq!(toml::from_str::<Answers>(&serialized).unwrap(), answers);
+    }
+
+    #[test]
+    fn test_check() {
+        let mut answers = Answers::default();
+        answers.record(1, "1500", Verdict::TooLow);
+        answers.record(1, "1651", Verdict::Correct);
+        answers.record(2, "2000", Verdict::TooHigh);
+        answers.record(2, "1200", Verdict::TooLow);
+        answers.record(2, "1650", Verdict::Incorrect);
+
+        assert_eq!(answers.check(1, "1651"), Check::Confirmed);
+        assert_eq!(
+            answers.check(1, "1652"),
+            Check::Changed {
+                confirmed: "1651".into()
+            }
+        );
+        assert_eq!(
+            answers.check(2, "1650"),
+            Check::Rejected(Verdict::Incorrect)
+        );
+        assert_eq!(
+            answers.check(2, "2100"),
+            Check::OutOfBounds {
+                guess: "2000".into(),
+                verdict: Verdict::TooHigh
+            }
+        );
+        assert_eq!(answers.check(2, "1200"), Check::Rejected(Verdict::TooLow));
+        assert_eq!(answers.check(2, "1707"), Check::Unverified);
+        assert_eq!(answers.check(2, "abc"), Check::Unverified);
+    }
+}
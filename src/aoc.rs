@@ -0,0 +1,302 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::puzzle;
+use anyhow::{anyhow, bail, Context, Result};
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Overrides the website base URL, e.g. to point tests at a local stub server.
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+const SESSION_ENV: &str = "ADVENT_OF_CODE_SESSION";
+const SESSION_FILE: &str = "adventofcode.session";
+const USER_AGENT: &str = "github.com/dannylongeuay/adventofcode";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubmitOutcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Incorrect,
+    RateLimited(Option<Duration>),
+    AlreadyCompleted,
+    Unknown(String),
+}
+
+pub struct Client {
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+        }
+    }
+
+    /// Builds a client from `AOC_BASE_URL` and the session cookie locations used by aoc-cli.
+    pub fn from_env() -> Result<Self> {
+        let base_url = env::var(BASE_URL_ENV).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+        Ok(Client::new(&base_url, &load_session()?))
+    }
+
+    fn get(&self, path: &str) -> Result<String> {
+        let url = format!("{}{}", self.base_url, path);
+        let response = ureq::get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", USER_AGENT)
+            .call()
+            .with_context(|| format!("could not download {}", url))?;
+        response
+            .into_string()
+            .with_context(|| format!("could not read response of {}", url))
+    }
+
+    pub fn fetch_input(&self, year: u16, day: u8) -> Result<String> {
+        self.get(&format!("/{}/day/{}/input", year, day))
+    }
+
+    /// Downloads the puzzle description as markdown, see `puzzle::html_to_markdown`.
+    pub fn fetch_puzzle(&self, year: u16, day: u8) -> Result<String> {
+        let page = self.get(&format!("/{}/day/{}", year, day))?;
+        let html = puzzle::puzzle_html(&page);
+        if html.is_empty() {
+            bail!("no puzzle description found for {} day {}", year, day);
+        }
+        Ok(puzzle::html_to_markdown(&html))
+    }
+
+    pub fn submit(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<SubmitOutcome> {
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let response = ureq::post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", USER_AGENT)
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])
+            .with_context(|| format!("could not submit answer to {}", url))?;
+        let body = response
+            .into_string()
+            .context("could not read submission response")?;
+        Ok(parse_submit_response(&body))
+    }
+}
+
+fn home_dir() -> Option<PathBuf> {
+    env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(PathBuf::from)
+}
+
+/// The platform's config directory, resolved like `dirs::config_dir`, which `aoc-cli` uses:
+/// `%APPDATA%` on Windows, `~/Library/Application Support` on macOS and `$XDG_CONFIG_HOME`
+/// or `~/.config` elsewhere.
+fn config_dir() -> Option<PathBuf> {
+    if cfg!(windows) {
+        env::var_os("APPDATA").map(PathBuf::from)
+    } else if cfg!(target_os = "macos") {
+        home_dir().map(|home| home.join("Library").join("Application Support"))
+    } else {
+        env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .filter(|dir| dir.is_absolute())
+            .or_else(|| home_dir().map(|home| home.join(".config")))
+    }
+}
+
+/// Session files in the order `aoc-cli` looks for them.
+fn session_paths() -> Vec<PathBuf> {
+    let mut paths = vec![];
+    if let Some(home) = home_dir() {
+        paths.push(home.join(format!(".{}", SESSION_FILE)));
+    }
+    if let Some(config) = config_dir() {
+        paths.push(config.join(SESSION_FILE));
+    }
+    paths
+}
+
+/// Loads the session cookie from `ADVENT_OF_CODE_SESSION` or an `adventofcode.session` file.
+pub fn load_session() -> Result<String> {
+    if let Ok(session) = env::var(SESSION_ENV) {
+        return Ok(session);
+    }
+    session_paths()
+        .iter()
+        .find_map(|path| fs::read_to_string(path).ok())
+        .ok_or_else(|| {
+            anyhow!(
+                "no session cookie found. Set {} or create ~/.{}",
+                SESSION_ENV,
+                SESSION_FILE
+            )
+        })
+}
+
+/// When a puzzle unlocks: midnight EST (UTC-5) on December `day`, as seconds since the unix epoch.
+pub fn unlock_time(year: u16, day: u8) -> u64 {
+    // days since the epoch of the civil date, see http://howardhinnant.github.io/date_algorithms.html
+    let (y, m, d) = (year as i64, 12_i64, day as i64);
+    let era = y.div_euclid(400);
+    let year_of_era = y - era * 400;
+    let day_of_year = (153 * (m - 3) + 2) / 5 + d - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146_097 + day_of_era - 719_468;
+    (days * 86_400 + 5 * 3600) as u64
+}
+
+/// Whether the puzzle of a day can be downloaded yet.
+pub fn is_unlocked(year: u16, day: u8) -> bool {
+    crate::unix_time() >= unlock_time(year, day)
+}
+
+fn parse_wait(text: &str) -> Option<Duration> {
+    let (before, _) = text.split_once(" left to wait")?;
+    let tokens = before.rsplit_once("You have ")?.1;
+    tokens
+        .split_whitespace()
+        .try_fold(Duration::ZERO, |acc, t| {
+            let (value, unit) = t.split_at(t.find(|c: char| !c.is_ascii_digit())?);
+            let value: u64 = value.parse().ok()?;
+            match unit {
+                "h" => Some(acc + Duration::from_secs(value * 3600)),
+                "m" => Some(acc + Duration::from_secs(value * 60)),
+                "s" => Some(acc + Duration::from_secs(value)),
+                _ => None,
+            }
+        })
+}
+
+/// Interprets the `<article>` of an answer submission response.
+pub fn parse_submit_response(html: &str) -> SubmitOutcome {
+    let article = html
+        .split_once("<article>")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(html, |(article, _)| article);
+
+    if article.contains("That's the right answer") {
+        SubmitOutcome::Correct
+    } else if article.contains("your answer is too high") {
+        SubmitOutcome::TooHigh
+    } else if article.contains("your answer is too low") {
+        SubmitOutcome::TooLow
+    } else if article.contains("That's not the right answer") {
+        SubmitOutcome::Incorrect
+    } else if article.contains("You gave an answer too recently") {
+        SubmitOutcome::RateLimited(parse_wait(article))
+    } else if article.contains("Did you already complete it") {
+        SubmitOutcome::AlreadyCompleted
+    } else {
+        SubmitOutcome::Unknown(article.trim().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    /// Serves a single request with `body` and hands the raw request back.
+    fn stub_server(body: &'static str) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(len) = line.to_lowercase().strip_prefix("content-length:") {
+                    content_length = len.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+            let mut payload = vec![0; content_length];
+            reader.read_exact(&mut payload).unwrap();
+            request.push_str(&String::from_utf8(payload).unwrap());
+            write!(
+                reader.get_mut(),
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            )
+            .unwrap();
+            request
+        });
+        (base_url, handle)
+    }
+
+    #[test]
+    fn test_parse_submit_response() {
+        assert_eq!(
+            parse_submit_response("<main><article><p>That's the right answer! You are one gold star closer.</p></article></main>"),
+            SubmitOutcome::Correct
+        );
+        assert_eq!(
+            parse_submit_response(
+                "<article><p>That's not the right answer; your answer is too low.</p></article>"
+            ),
+            SubmitOutcome::TooLow
+        );
+        assert_eq!(
+            parse_submit_response("<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.</p></article>"),
+            SubmitOutcome::RateLimited(Some(Duration::from_secs(65)))
+        );
+        assert_eq!(
+            parse_submit_response("<article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article>"),
+            SubmitOutcome::AlreadyCompleted
+        );
+    }
+
+    #[test]
+    fn test_unlock_time() {
+        assert_eq!(unlock_time(2022, 1), 1_669_870_800);
+        assert_eq!(unlock_time(2015, 25), 1_451_019_600);
+        assert!(is_unlocked(2022, 25));
+    }
+
+    #[test]
+    fn test_submit() {
+        let (base_url, handle) = stub_server(
+            "<article><p>That's not the right answer; your answer is too high.</p></article>",
+        );
+        let client = Client::new(&base_url, "abc123\n");
+
+        let outcome = client.submit(2022, 16, 2, "1707").unwrap();
+        let request = handle.join().unwrap();
+
+        assert_eq!(outcome, SubmitOutcome::TooHigh);
+        assert!(request.starts_with("POST /2022/day/16/answer HTTP/1.1"));
+        assert!(request.contains("session=abc123\r\n"));
+        assert!(request.ends_with("level=2&answer=1707"));
+    }
+
+    #[test]
+    fn test_fetch_puzzle() {
+        let (base_url, handle) = stub_server(
+            "<main><article class=\"day-desc\"><h2>--- Day 1: Calorie Counting ---</h2>\n<p>Like this:</p>\n<pre><code>1000\n\n&lt;2000&gt;\n</code></pre>\n<p>This is <code><em>24000</em></code>.</p>\n</article>\n<p>Your puzzle answer was <code>69501</code>.</p><form></form></main>",
+        );
+        let client = Client::new(&base_url, "abc123");
+
+        let puzzle = client.fetch_puzzle(2022, 1).unwrap();
+        let request = handle.join().unwrap();
+
+        assert!(request.starts_with("GET /2022/day/1 HTTP/1.1"));
+        assert_eq!(
+            puzzle,
+            "## --- Day 1: Calorie Counting ---\n\nLike this:\n\n```\n1000\n\n<2000>\n```\n\nThis is `*24000*`.\n\nYour puzzle answer was `69501`.\n"
+        );
+    }
+}
@@ -0,0 +1,180 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::results::{IntoAnswer, PartResult};
+use serde::{Deserialize, Serialize};
+use std::env;
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+
+/// Enables benchmarking in `solve!`, formatted as `<max runs>:<time budget in ms>`.
+pub const BENCH_ENV: &str = "AOC_BENCH";
+
+const WARMUP_RUNS: u32 = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchConfig {
+    pub runs: u32,
+    pub budget: Duration,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        BenchConfig {
+            runs: 1000,
+            budget: Duration::from_secs(5),
+        }
+    }
+}
+
+impl BenchConfig {
+    pub fn from_env() -> Option<Self> {
+        let value = env::var(BENCH_ENV).ok()?;
+        let (runs, budget) = value.split_once(':')?;
+        Some(BenchConfig {
+            runs: runs.parse().ok()?,
+            budget: Duration::from_millis(budget.parse().ok()?),
+        })
+    }
+
+    pub fn to_env(&self) -> String {
+        format!("{}:{}", self.runs, self.budget.as_millis())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BenchStats {
+    pub runs: u32,
+    pub min: u64,
+    pub median: u64,
+    pub mean: f64,
+    pub stddev: f64,
+}
+
+impl BenchStats {
+    /// Computes statistics over non-empty `samples` of nanoseconds.
+    pub fn from_samples(samples: &[u64]) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+        let n = sorted.len();
+        let median = match n % 2 {
+            0 => (sorted[n / 2 - 1] + sorted[n / 2]) / 2,
+            _ => sorted[n / 2],
+        };
+        let mean = sorted.iter().sum::<u64>() as f64 / n as f64;
+        let variance = match n {
+            1 => 0_f64,
+            _ => {
+                sorted
+                    .iter()
+                    .map(|&s| (s as f64 - mean).powi(2))
+                    .sum::<f64>()
+                    / (n - 1) as f64
+            }
+        };
+        BenchStats {
+            runs: n as u32,
+            min: sorted[0],
+            median,
+            mean,
+            stddev: variance.sqrt(),
+        }
+    }
+}
+
+impl Display for BenchStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let nanos = |n: f64| Duration::from_nanos(n as u64);
+        write!(
+            f,
+            "min: {:.2?}, median: {:.2?}, mean: {:.2?} ± {:.2?}, {} runs",
+            nanos(self.min as f64),
+            nanos(self.median as f64),
+            nanos(self.mean),
+            nanos(self.stddev),
+            self.runs
+        )
+    }
+}
+
+/// Runs `func` after a warmup until `config.runs` runs or `config.budget` is used up.
+///
+/// Warmup runs count against the budget, so a part takes at most one run longer than the
+/// budget. A run that uses up the budget during the warmup becomes the only sample.
+pub fn bench<T>(config: &BenchConfig, func: impl Fn() -> T) -> (T, BenchStats) {
+    let started = Instant::now();
+    let mut warmups = 0;
+    let mut samples: Vec<u64> = vec![];
+    loop {
+        let timer = Instant::now();
+        let result = func();
+        let nanos = timer.elapsed().as_nanos() as u64;
+        let out_of_budget = started.elapsed() >= config.budget;
+        if warmups < WARMUP_RUNS && !out_of_budget {
+            warmups += 1;
+            continue;
+        }
+        samples.push(nanos);
+        if samples.len() as u32 >= config.runs || out_of_budget {
+            return (result, BenchStats::from_samples(&samples));
+        }
+    }
+}
+
+/// Runs a single part, timing it once or benchmarking it if `AOC_BENCH` is set.
+pub fn measure<T: IntoAnswer>(part: u8, func: impl Fn(&str) -> T, input: &str) -> PartResult {
+    let (answer, nanos, bench) = match BenchConfig::from_env() {
+        Some(config) => {
+            let (answer, stats) = bench(&config, || func(input));
+            (answer, stats.median, Some(stats))
+        }
+        None => {
+            let timer = Instant::now();
+            let answer = func(input);
+            (answer, timer.elapsed().as_nanos() as u64, None)
+        }
+    };
+    PartResult {
+        bench,
+        ..PartResult::new(part, answer.into_answer(), nanos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let stats = BenchStats::from_samples(&[40, 10, 30, 20]);
+        assert_eq!(stats.runs, 4);
+        assert_eq!(stats.min, 10);
+        assert_eq!(stats.median, 25);
+        assert_eq!(stats.mean, 25_f64);
+        assert!((stats.stddev - 12.909_944).abs() < 1.0e-6);
+
+        let single = BenchStats::from_samples(&[7]);
+        assert_eq!((single.median, single.stddev), (7, 0_f64));
+    }
+
+    #[test]
+    fn test_bench_stops_after_runs() {
+        let config = BenchConfig {
+            runs: 5,
+            budget: Duration::from_secs(60),
+        };
+        let (result, stats) = bench(&config, || 42);
+        assert_eq!(result, 42);
+        assert_eq!(stats.runs, 5);
+
+        // a slow part uses up the budget during the warmup and is not run again.
+        let calls = std::cell::Cell::new(0);
+        let config = BenchConfig {
+            runs: 5,
+            budget: Duration::ZERO,
+        };
+        let (_, stats) = bench(&config, || calls.set(calls.get() + 1));
+        assert_eq!((calls.get(), stats.runs), (1, 1));
+    }
+}
@@ -91,7 +91,7 @@ fn score_round_2(a: &str, b: &str) -> u32 {
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 2022, 2);
+    let input = &advent_of_code::read_input(2022, 2);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
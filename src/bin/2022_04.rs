@@ -15,9 +15,8 @@ pub fn part_one(input: &str) -> Option<u32> {
         let s_end = second_sections[1].parse::<u32>().unwrap();
         let f_set: HashSet<u32> = HashSet::from_iter(f_start..=f_end);
         let s_set: HashSet<u32> = HashSet::from_iter(s_start..=s_end);
-        if f_set.difference(&s_set).sum::<u32>() == 0 {
-            sum += 1;
-        } else if s_set.difference(&f_set).sum::<u32>() == 0 {
+        if f_set.difference(&s_set).sum::<u32>() == 0 || s_set.difference(&f_set).sum::<u32>() == 0
+        {
             sum += 1;
         }
     }
@@ -47,7 +46,7 @@ pub fn part_two(input: &str) -> Option<u32> {
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 2022, 4);
+    let input = &advent_of_code::read_input(2022, 4);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
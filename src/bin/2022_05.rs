@@ -1,23 +1,23 @@
 use std::collections::HashMap;
 
 enum Phase {
-    STACKS,
-    MOVES,
+    Stacks,
+    Moves,
 }
 
 pub fn part_one(input: &str) -> Option<String> {
     let lines = input.lines();
-    let mut phase = Phase::STACKS;
+    let mut phase = Phase::Stacks;
     let mut stacks: HashMap<usize, Vec<char>> = HashMap::new();
     for line in lines {
         if line.starts_with("m") {
-            phase = Phase::MOVES;
+            phase = Phase::Moves;
         }
         match phase {
-            Phase::STACKS => {
+            Phase::Stacks => {
                 fill_stacks(line, &mut stacks);
             }
-            Phase::MOVES => {
+            Phase::Moves => {
                 move_crates(line, &mut stacks, false);
             }
         }
@@ -27,17 +27,17 @@ pub fn part_one(input: &str) -> Option<String> {
 
 pub fn part_two(input: &str) -> Option<String> {
     let lines = input.lines();
-    let mut phase = Phase::STACKS;
+    let mut phase = Phase::Stacks;
     let mut stacks: HashMap<usize, Vec<char>> = HashMap::new();
     for line in lines {
         if line.starts_with("m") {
-            phase = Phase::MOVES;
+            phase = Phase::Moves;
         }
         match phase {
-            Phase::STACKS => {
+            Phase::Stacks => {
                 fill_stacks(line, &mut stacks);
             }
-            Phase::MOVES => {
+            Phase::Moves => {
                 move_crates(line, &mut stacks, true);
             }
         }
@@ -64,12 +64,7 @@ fn fill_stacks(line: &str, stacks: &mut HashMap<usize, Vec<char>>) {
             if !c.is_alphabetic() {
                 continue;
             }
-            if stacks.contains_key(&stack_num) {
-                let stack = stacks.get_mut(&stack_num).unwrap();
-                stack.insert(0, c);
-            } else {
-                stacks.insert(stack_num, vec![c]);
-            }
+            stacks.entry(stack_num).or_default().insert(0, c);
         }
     }
 }
@@ -93,7 +88,7 @@ fn move_crates(line: &str, stacks: &mut HashMap<usize, Vec<char>>, move_multi: b
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 2022, 5);
+    let input = &advent_of_code::read_input(2022, 5);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
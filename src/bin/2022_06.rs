@@ -22,7 +22,7 @@ fn first_unique_window(input: &str, win_size: usize) -> Option<usize> {
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 2022, 6);
+    let input = &advent_of_code::read_input(2022, 6);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
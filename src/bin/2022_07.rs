@@ -1,10 +1,32 @@
+use serde::Deserialize;
 use std::collections::HashMap;
 
+#[derive(Debug, Deserialize)]
+#[serde(default)]
+struct Params {
+    /// Largest size of the directories summed up in part one.
+    max_dir_size: usize,
+    disk_size: usize,
+    /// Unused space the update needs.
+    required_space: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            max_dir_size: 100_000,
+            disk_size: 70_000_000,
+            required_space: 30_000_000,
+        }
+    }
+}
+
 pub fn part_one(input: &str) -> Option<usize> {
     let dir_sizes = calc_dir_sizes(input);
+    let params: Params = advent_of_code::params();
 
     Some(dir_sizes.into_values().fold(0, |acc, size| {
-        if size <= 100_000 {
+        if size <= params.max_dir_size {
             return acc + size;
         }
         acc
@@ -13,12 +35,13 @@ pub fn part_one(input: &str) -> Option<usize> {
 
 pub fn part_two(input: &str) -> Option<usize> {
     let dir_sizes = calc_dir_sizes(input);
+    let params: Params = advent_of_code::params();
 
-    let unused_space = 70_000_000 - dir_sizes.get("/").unwrap();
+    let unused_space = params.disk_size - dir_sizes.get("/").unwrap();
 
     let mut smallest = usize::MAX;
     for (_k, v) in dir_sizes.iter() {
-        if *v + unused_space < 30_000_000 {
+        if *v + unused_space < params.required_space {
             continue;
         }
         if *v < smallest {
@@ -69,7 +92,7 @@ fn calc_dir_sizes(input: &str) -> HashMap<String, usize> {
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 2022, 7);
+    let input = &advent_of_code::read_input(2022, 7);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
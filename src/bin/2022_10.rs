@@ -84,13 +84,13 @@ pub fn part_two(input: &str) -> Option<i32> {
         for x in 0..WIDTH {
             print!("{}", message[(WIDTH * y + x) as usize]);
         }
-        println!("");
+        println!();
     }
     None
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 2022, 10);
+    let input = &advent_of_code::read_input(2022, 10);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -1,4 +1,4 @@
-use anyhow::Error;
+use advent_of_code::helpers::{parse_token, ParseError};
 use std::str::FromStr;
 
 #[derive(Debug)]
@@ -17,7 +17,7 @@ impl Monkey {
             operation: Operation {
                 a: "".to_string(),
                 b: "".to_string(),
-                operand: Operand::ADD,
+                operand: Operand::Add,
             },
             test_condition: 0,
             true_throw: 0,
@@ -28,8 +28,8 @@ impl Monkey {
 
 #[derive(Debug)]
 enum Operand {
-    ADD,
-    MULTIPLY,
+    Add,
+    Multiply,
 }
 
 #[derive(Debug)]
@@ -50,51 +50,67 @@ impl Operation {
             _ => self.b.parse::<usize>().unwrap(),
         };
         match self.operand {
-            Operand::ADD => aa + bb,
-            Operand::MULTIPLY => aa * bb,
+            Operand::Add => aa + bb,
+            Operand::Multiply => aa * bb,
         }
     }
 }
 
+/// Parses an `Operation: new = <a> <operand> <b>` line.
 impl FromStr for Operation {
-    type Err = Error;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let words: Vec<&str> = s.trim().split(" ").collect();
+        let words: Vec<&str> = s.split_whitespace().collect();
+        let (a, operand, b) = match words[..] {
+            ["Operation:", "new", "=", a, operand, b] => (a, operand, b),
+            _ => {
+                return Err(ParseError::new(
+                    s,
+                    s.trim(),
+                    "\"Operation: new = <a> <operand> <b>\"",
+                ))
+            }
+        };
+        for value in [a, b] {
+            if value != "old" {
+                parse_token::<usize>(s, value, "\"old\" or a number")?;
+            }
+        }
         Ok(Operation {
-            a: words[2].to_string(),
-            b: words[4].to_string(),
-            operand: match words[3] {
-                "+" => Operand::ADD,
-                "*" => Operand::MULTIPLY,
-                _ => unreachable!("bad input for operand"),
+            a: a.to_string(),
+            b: b.to_string(),
+            operand: match operand {
+                "+" => Operand::Add,
+                "*" => Operand::Multiply,
+                _ => return Err(ParseError::new(s, operand, "\"+\" or \"*\"")),
             },
         })
     }
 }
 
-pub fn part_one(input: &str) -> Option<usize> {
-    let mut monkeys = get_monkeys(input);
-    Some(get_inspects(&mut monkeys, 20, true))
+pub fn part_one(input: &str) -> Result<usize, ParseError> {
+    let mut monkeys = get_monkeys(input)?;
+    Ok(get_inspects(&mut monkeys, 20, true))
 }
 
-pub fn part_two(input: &str) -> Option<usize> {
-    let mut monkeys = get_monkeys(input);
-    Some(get_inspects(&mut monkeys, 10000, false))
+pub fn part_two(input: &str) -> Result<usize, ParseError> {
+    let mut monkeys = get_monkeys(input)?;
+    Ok(get_inspects(&mut monkeys, 10000, false))
 }
 
-fn get_inspects(monkeys: &mut Vec<Monkey>, rounds: usize, should_divde: bool) -> usize {
+fn get_inspects(monkeys: &mut [Monkey], rounds: usize, should_divde: bool) -> usize {
     let mut inspects: Vec<usize> = vec![0; monkeys.len()];
     let monkey_lcm = monkeys.iter().map(|m| m.test_condition).product::<usize>();
     for _round in 1..=rounds {
-        for j in 0..monkeys.len() {
+        for (j, inspect) in inspects.iter_mut().enumerate() {
             let monkey_thrower = monkeys.get_mut(j).unwrap();
             let true_throw = monkey_thrower.true_throw;
             let false_throw = monkey_thrower.false_throw;
             let mut true_throws: Vec<usize> = Vec::new();
             let mut false_throws: Vec<usize> = Vec::new();
             while let Some(old_item) = monkey_thrower.items.pop() {
-                inspects[j] += 1;
+                *inspect += 1;
                 let mut new_item = monkey_thrower.operation.run(old_item);
                 if should_divde {
                     new_item /= 3;
@@ -117,59 +133,55 @@ fn get_inspects(monkeys: &mut Vec<Monkey>, rounds: usize, should_divde: bool) ->
     inspects.iter().rev().take(2).product::<usize>()
 }
 
-fn get_monkeys(input: &str) -> Vec<Monkey> {
-    let monkey_stats: Vec<&str> = input.split("\n\n").collect();
-    let mut monkeys: Vec<Monkey> = Vec::new();
-    for stats in monkey_stats.iter() {
-        let lines = stats.lines();
-        let mut monkey = Monkey::new();
-        for line in lines {
-            let words: Vec<&str> = line.trim().split(":").collect();
-            match words[0] {
-                "Starting items" => {
-                    monkey.items = words[1]
-                        .trim()
-                        .split(", ")
-                        .map(|i| i.parse::<usize>().unwrap())
-                        .collect();
-                }
-                "Operation" => {
-                    monkey.operation = Operation::from_str(words[1]).unwrap();
-                }
-                "Test" => {
-                    monkey.test_condition = words[1]
-                        .split(" ")
-                        .last()
-                        .unwrap()
-                        .parse::<usize>()
-                        .unwrap();
-                }
-                "If true" => {
-                    monkey.true_throw = words[1]
-                        .split(" ")
-                        .last()
-                        .unwrap()
-                        .parse::<usize>()
-                        .unwrap();
-                }
-                "If false" => {
-                    monkey.false_throw = words[1]
-                        .split(" ")
-                        .last()
-                        .unwrap()
-                        .parse::<usize>()
-                        .unwrap();
-                }
-                _ => {}
-            }
+fn get_monkeys(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    let mut monkeys: Vec<Monkey> = vec![Monkey::new()];
+    for (i, line) in input.lines().enumerate() {
+        if line.is_empty() {
+            monkeys.push(Monkey::new());
+            continue;
+        }
+        let monkey = monkeys.last_mut().unwrap();
+        parse_monkey_line(monkey, line).map_err(|e| e.on_line(i + 1))?;
+    }
+    Ok(monkeys)
+}
+
+fn parse_monkey_line(monkey: &mut Monkey, line: &str) -> Result<(), ParseError> {
+    let (key, value) = line
+        .trim()
+        .split_once(':')
+        .ok_or_else(|| ParseError::at_end(line, "\":\""))?;
+    let last_number = || {
+        let word = value.split_whitespace().last().unwrap_or(value);
+        parse_token::<usize>(line, word, "a number")
+    };
+    match key {
+        "Starting items" => {
+            monkey.items = value
+                .trim()
+                .split(", ")
+                .map(|i| parse_token(line, i, "a worry level"))
+                .collect::<Result<_, _>>()?;
+        }
+        "Operation" => {
+            monkey.operation = Operation::from_str(line)?;
+        }
+        "Test" => {
+            monkey.test_condition = last_number()?;
+        }
+        "If true" => {
+            monkey.true_throw = last_number()?;
+        }
+        "If false" => {
+            monkey.false_throw = last_number()?;
         }
-        monkeys.push(monkey);
+        _ => {}
     }
-    monkeys
+    Ok(())
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 2022, 11);
+    let input = &advent_of_code::read_input(2022, 11);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -181,12 +193,12 @@ mod tests {
     #[test]
     fn test_part_one() {
         let input = advent_of_code::read_file("examples", 2022, 11);
-        assert_eq!(part_one(&input), Some(10605));
+        assert_eq!(part_one(&input), Ok(10605));
     }
 
     #[test]
     fn test_part_two() {
         let input = advent_of_code::read_file("examples", 2022, 11);
-        assert_eq!(part_two(&input), Some(2713310158));
+        assert_eq!(part_two(&input), Ok(2713310158));
     }
 }
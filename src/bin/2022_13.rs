@@ -87,7 +87,7 @@ fn build_packet(chars: &mut Chars) -> Packet {
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 2022, 13);
+    let input = &advent_of_code::read_input(2022, 13);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -1,224 +1,103 @@
-use std::{
-    collections::HashSet,
-    fmt::Display,
-    ops::{Add, Sub},
-};
+use advent_of_code::helpers::{Dir8, Point2, SparseGrid};
 use Tile::*;
 
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
-struct Point {
-    x: i32,
-    y: i32,
-}
-
-impl Point {
-    fn new(x: i32, y: i32) -> Self {
-        Point { x, y }
-    }
-}
-
-impl Add for Point {
-    type Output = Self;
-    fn add(self, other: Self) -> Self {
-        Point {
-            x: self.x + other.x,
-            y: self.y + other.y,
-        }
-    }
-}
+type Point = Point2<i64>;
 
-impl Sub for Point {
-    type Output = Self;
-    fn sub(self, other: Self) -> Self {
-        Point {
-            x: self.x - other.x,
-            y: self.y - other.y,
-        }
-    }
-}
+const SAND_SOURCE: Point = Point::new(500, 0);
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 enum Tile {
-    EMPTY,
-    ROCK,
-    SAND,
+    Empty,
+    Rock,
+    Sand,
 }
 
 #[derive(Debug)]
-struct Grid {
-    map: Vec<Tile>,
-    width: i32,
-    height: i32,
-    sand_source: Point,
+struct Cave {
+    tiles: SparseGrid<Tile>,
+    /// Lowest rock, below which sand falls into the abyss unless there is a floor.
+    y_max: i64,
+    /// Row of the infinite floor, if any.
+    floor: Option<i64>,
 }
 
-impl Grid {
+impl Cave {
     fn new(input: &str, infinite_floor: bool) -> Self {
-        let mut x_min = i32::MAX;
-        let mut x_max = i32::MIN;
-        let mut y_max = i32::MIN;
-        let mut rock_points: HashSet<Point> = HashSet::new();
+        let mut tiles = SparseGrid::new(Empty);
         for path in input.lines() {
             let points = path.split(" -> ");
             let mut prev: Option<Point> = None;
             for point_raw in points {
                 let (x, y) = point_raw.split_once(",").unwrap();
-                let current_point =
-                    Point::new(x.parse::<i32>().unwrap(), y.parse::<i32>().unwrap());
-                x_min = x_min.min(current_point.x);
-                x_max = x_max.max(current_point.x);
-                y_max = y_max.max(current_point.y);
+                let current_point = Point::new(x.parse().unwrap(), y.parse().unwrap());
                 if let Some(prev_point) = prev {
-                    let dir = current_point - prev_point;
-                    if dir.x > 0 {
-                        for i in 0..=dir.x {
-                            rock_points.insert(prev_point + Point::new(i, 0));
-                        }
-                    } else if dir.x < 0 {
-                        for i in 0..=dir.x.abs() {
-                            rock_points.insert(prev_point + Point::new(-i, 0));
-                        }
-                    } else if dir.y > 0 {
-                        for i in 0..=dir.y {
-                            rock_points.insert(prev_point + Point::new(0, i));
-                        }
-                    } else if dir.y < 0 {
-                        for i in 0..=dir.y.abs() {
-                            rock_points.insert(prev_point + Point::new(0, -i));
-                        }
-                    } else {
-                        unreachable!("invalid direction {:?}", dir);
+                    let step = (current_point - prev_point).signum();
+                    let mut point = prev_point;
+                    tiles.insert(point, Rock);
+                    while point != current_point {
+                        point += step;
+                        tiles.insert(point, Rock);
                     }
                 }
                 prev = Some(current_point);
             }
         }
-        let mut width = x_max - x_min + 1;
-        let mut height = y_max + 1;
-        if infinite_floor {
-            width = x_max * 2;
-            height += 2;
-        }
-        let mut map: Vec<Tile> = vec![EMPTY; (width * height) as usize];
-        let mut sand_source = Point::new(500 - x_min, 0);
-        if infinite_floor {
-            sand_source = Point::new(500, 0);
-        }
-        for rock_point in rock_points {
-            let mut index = (rock_point.y * width + rock_point.x - x_min) as usize;
-            if infinite_floor {
-                index = (rock_point.y * width + rock_point.x) as usize;
-            }
-            map[index] = ROCK;
-        }
-        if infinite_floor {
-            map[(sand_source.y * width + sand_source.x) as usize] = SAND;
-            for x in 0..width {
-                let index = ((height - 1) * width + x) as usize;
-                map[index] = ROCK;
-            }
-        }
-        Grid {
-            map,
-            width,
-            height,
-            sand_source,
+        let y_max = tiles.bounds().map_or(0, |(_, max)| max.y);
+        let floor = infinite_floor.then_some(y_max + 2);
+        Cave {
+            tiles,
+            y_max,
+            floor,
         }
     }
 
-    fn get(&self, p: &Point) -> Option<Tile> {
-        self.map.get((p.y * self.width + p.x) as usize).copied()
+    fn get(&self, p: Point) -> Tile {
+        match self.floor {
+            Some(floor) if p.y == floor => Rock,
+            _ => self.tiles[p],
+        }
     }
 
-    fn simulate(&mut self) {
-        let mut next_point = self.sand_source + Point::new(0, 1);
-
-        let mut safety = 0;
-        while let Some(tile) = self.get(&next_point) {
-            match tile {
-                EMPTY => {
-                    next_point = next_point + Point::new(0, 1);
-                }
-                _ => {
-                    let left_point = next_point + Point::new(-1, 0);
-                    let right_point = next_point + Point::new(1, 0);
-                    let left = self.get(&left_point);
-                    let right = self.get(&right_point);
-                    match (left, right) {
-                        // hit left edge of map, sand will fall endlessly
-                        (None, Some(_)) => {
-                            break;
-                        }
-                        // hit right edge of map, sand will fall endlessly
-                        (Some(_), None) => {
-                            break;
-                        }
-                        // left point is empty
-                        (Some(EMPTY), Some(_)) => {
-                            next_point = left_point;
-                        }
-                        // left is occupied and right point is empty
-                        (Some(_), Some(EMPTY)) => {
-                            next_point = right_point;
-                        }
-                        // left and right are occupied
-                        (Some(_), Some(_)) => {
-                            self.map[((next_point.y - 1) * self.width + next_point.x) as usize] =
-                                SAND;
-                            next_point = self.sand_source + Point::new(0, 1);
-                        }
-                        (None, None) => {
-                            unreachable!("both left and right points should not be None");
-                        }
-                    }
+    /// Drops a unit of sand from the source, returning where it comes to rest. Returns `None`
+    /// once sand falls into the abyss or the source is blocked.
+    fn drop_sand(&mut self) -> Option<Point> {
+        let mut point = SAND_SOURCE;
+        if self.get(point) != Empty {
+            return None;
+        }
+        'falling: while self.floor.is_some() || point.y <= self.y_max {
+            for dir in [Dir8::Down, Dir8::DownLeft, Dir8::DownRight] {
+                let next_point = point + dir.offset();
+                if self.get(next_point) == Empty {
+                    point = next_point;
+                    continue 'falling;
                 }
             }
-            safety += 1;
-            if safety > 10_000_000 {
-                break;
-            }
+            self.tiles.insert(point, Sand);
+            return Some(point);
         }
+        None
     }
-}
 
-impl Display for Grid {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for y in 0..self.height {
-            for x in 0..self.width {
-                match self.get(&Point::new(x, y)).unwrap() {
-                    EMPTY => {
-                        write!(f, ".")?;
-                    }
-                    ROCK => {
-                        write!(f, "#")?;
-                    }
-                    SAND => {
-                        write!(f, "+")?;
-                    }
-                }
-            }
-            write!(f, "\n")?;
-        }
-        write!(f, "\n")
+    fn simulate(&mut self) {
+        while self.drop_sand().is_some() {}
     }
 }
 
 pub fn part_one(input: &str) -> Option<usize> {
-    let mut grid = Grid::new(input, false);
-    grid.simulate();
-    println!("{}", grid);
-    Some(grid.map.iter().filter(|&t| t == &SAND).count())
+    let mut cave = Cave::new(input, false);
+    cave.simulate();
+    Some(cave.tiles.values().filter(|&t| t == &Sand).count())
 }
 
 pub fn part_two(input: &str) -> Option<usize> {
-    let mut grid = Grid::new(input, true);
-    grid.simulate();
-    // println!("{}", grid);
-    Some(grid.map.iter().filter(|&t| t == &SAND).count())
+    let mut cave = Cave::new(input, true);
+    cave.simulate();
+    Some(cave.tiles.values().filter(|&t| t == &Sand).count())
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 2022, 14);
+    let input = &advent_of_code::read_input(2022, 14);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
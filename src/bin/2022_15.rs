@@ -1,61 +1,70 @@
-use anyhow::Error;
+use advent_of_code::helpers::{parse_lines, parse_token, ParseError, Point2};
+use anyhow::{bail, Result};
+use serde::Deserialize;
 use std::{collections::HashSet, str::FromStr};
 
-pub fn part_one(input: &str) -> Option<i64> {
-    let sensors: Vec<Sensor> = input
-        .lines()
-        .map(|l| Sensor::from_str(l).unwrap())
-        .collect();
-    // example row
-    let mut row: i64 = 10;
-    if sensors.len() > 14 {
-        // input row
-        row = 2_000_000;
+#[derive(Debug, Deserialize)]
+#[serde(default)]
+struct Params {
+    /// Row to count the positions without a beacon on.
+    row: i64,
+    /// Largest coordinate the distress beacon can be at.
+    max_range: i64,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            row: 2_000_000,
+            max_range: 4_000_000,
+        }
     }
-    let beacons_on_row: HashSet<(i64, i64)> = sensors
+}
+
+pub fn part_one(input: &str) -> Result<i64> {
+    let sensors: Vec<Sensor> = parse_lines(input)?;
+    let row = advent_of_code::params::<Params>().row;
+    let beacons_on_row: HashSet<Point2<i64>> = sensors
         .iter()
-        .filter(|s| s.beacon.1 == row)
+        .filter(|s| s.beacon.y == row)
         .map(|s| s.beacon)
         .collect();
-    Some(
-        merged_ranges(&sensors, row)
-            .iter()
-            .fold(0, |acc, r| acc + r.end - r.start + 1)
-            - beacons_on_row.len() as i64,
-    )
+    Ok(merged_ranges(&sensors, row)
+        .iter()
+        .fold(0, |acc, r| acc + r.end - r.start + 1)
+        - beacons_on_row.len() as i64)
 }
 
-const MAX_RANGE: i64 = 4_000_000;
+const TUNING_MULTIPLIER: i64 = 4_000_000;
 
-pub fn part_two(input: &str) -> Option<i64> {
-    let sensors: Vec<Sensor> = input
-        .lines()
-        .map(|l| Sensor::from_str(l).unwrap())
-        .collect();
-    for row in 0..=MAX_RANGE {
+pub fn part_two(input: &str) -> Result<i64> {
+    let sensors: Vec<Sensor> = parse_lines(input)?;
+    for row in 0..=advent_of_code::params::<Params>().max_range {
         let merged = merged_ranges(&sensors, row);
         if merged.len() > 1 {
-            return Some((merged[0].end + 1) * MAX_RANGE + row);
+            return Ok((merged[0].end + 1) * TUNING_MULTIPLIER + row);
         }
     }
-    None
+    bail!("no position is out of range of every sensor")
 }
 
 #[derive(Debug)]
 struct Sensor {
-    loc: (i64, i64),
-    beacon: (i64, i64),
+    loc: Point2<i64>,
+    beacon: Point2<i64>,
     distance: u32,
 }
 
 impl FromStr for Sensor {
-    type Err = Error;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (first, second) = s.split_once(":").unwrap();
-        let sensor = parse_coordinate(first);
-        let beacon = parse_coordinate(second);
-        let distance = manhattan_distance(sensor, beacon);
+        let (first, second) = s
+            .split_once(':')
+            .ok_or_else(|| ParseError::at_end(s, "\":\""))?;
+        let sensor = parse_coordinate(s, first)?;
+        let beacon = parse_coordinate(s, second)?;
+        let distance = sensor.manhattan(beacon) as u32;
         Ok(Sensor {
             loc: sensor,
             beacon,
@@ -117,32 +126,32 @@ fn merged_ranges(sensors: &Vec<Sensor>, row: i64) -> Vec<Range> {
 }
 
 fn sensor_range(sensor: &Sensor, row: i64) -> Option<Range> {
-    let h = sensor.distance as i64 - (row - sensor.loc.1).abs();
+    let h = sensor.distance as i64 - (row - sensor.loc.y).abs();
     if h <= 0 {
         return None;
     }
-    Some(Range::new(sensor.loc.0 - h, sensor.loc.0 + h))
-}
-
-fn manhattan_distance(a: (i64, i64), b: (i64, i64)) -> u32 {
-    ((b.0 - a.0).abs() + (b.1 - a.1).abs()) as u32
+    Some(Range::new(sensor.loc.x - h, sensor.loc.x + h))
 }
 
-fn parse_coordinate(s: &str) -> (i64, i64) {
-    let (first, second) = s.split_once(", ").unwrap();
-    let first_terms = first.split_whitespace();
-    let second_terms = second.split_whitespace();
-    let x_coord = parse_axis(first_terms.last().unwrap());
-    let y_coord = parse_axis(second_terms.last().unwrap());
-    (x_coord, y_coord)
+/// Parses `x=<x>, y=<y>` at the end of `s`, a slice of `line`.
+fn parse_coordinate(line: &str, s: &str) -> Result<Point2<i64>, ParseError> {
+    let (first, second) = s
+        .split_once(", ")
+        .ok_or_else(|| ParseError::new(line, s, "\"x=<x>, y=<y>\""))?;
+    let x_coord = parse_axis(line, first.split_whitespace().last().unwrap_or(first))?;
+    let y_coord = parse_axis(line, second.split_whitespace().last().unwrap_or(second))?;
+    Ok(Point2::new(x_coord, y_coord))
 }
 
-fn parse_axis(s: &str) -> i64 {
-    s.split_once("=").unwrap().1.parse::<i64>().unwrap()
+fn parse_axis(line: &str, s: &str) -> Result<i64, ParseError> {
+    let (_, value) = s
+        .split_once('=')
+        .ok_or_else(|| ParseError::new(line, s, "\"<axis>=<value>\""))?;
+    parse_token(line, value, "a number")
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 2022, 15);
+    let input = &advent_of_code::read_input(2022, 15);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -150,16 +159,15 @@ fn main() {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use advent_of_code::examples;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 2022, 15);
-        assert_eq!(part_one(&input), Some(26));
+        examples::check(2022, 15, 1, part_one);
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 2022, 15);
-        assert_eq!(part_two(&input), Some(56000011));
+        examples::check(2022, 15, 2, part_two);
     }
 }
@@ -3,27 +3,30 @@ use std::{
     str::FromStr,
 };
 
+use anyhow::{bail, Context, Result};
 use once_cell::sync::Lazy;
 use std::sync::Mutex;
 
-pub fn part_one(input: &str) -> Option<u64> {
-    populate_bit_valves(input);
-    Some(score(pack_state(0, 30, 0, 0)))
+pub fn part_one(input: &str) -> Result<u64> {
+    populate_bit_valves(input)?;
+    Ok(score(pack_state(0, 30, 0, 0)))
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    populate_bit_valves(input);
-    Some(score(pack_state(0, 26, 0, 1)))
+pub fn part_two(input: &str) -> Result<u64> {
+    populate_bit_valves(input)?;
+    Ok(score(pack_state(0, 26, 0, 1)))
 }
 
-fn populate_bit_valves(input: &str) {
+fn populate_bit_valves(input: &str) -> Result<()> {
     let string_valves: BTreeMap<String, Valve<String>> = input
         .lines()
-        .map(|l| {
-            let v = Valve::from_str(l).unwrap();
-            (v.name.clone(), v)
+        .enumerate()
+        .map(|(i, l)| {
+            let v =
+                Valve::from_str(l).with_context(|| format!("invalid valve on line {}", i + 1))?;
+            Ok((v.name.clone(), v))
         })
-        .collect();
+        .collect::<Result<_>>()?;
     let valve_indexes: BTreeMap<String, u64> = string_valves
         .iter()
         .enumerate()
@@ -31,7 +34,16 @@ fn populate_bit_valves(input: &str) {
         .collect();
     for (k, v) in string_valves.iter() {
         let name = valve_indexes[k];
-        let conns: Vec<u64> = v.conns.iter().map(|c| valve_indexes[c]).collect();
+        let conns: Vec<u64> = v
+            .conns
+            .iter()
+            .map(|c| {
+                valve_indexes
+                    .get(c)
+                    .copied()
+                    .with_context(|| format!("valve {} leads to unknown valve {}", k, c))
+            })
+            .collect::<Result<_>>()?;
         BIT_VALVES.lock().unwrap().insert(
             name,
             Valve {
@@ -41,6 +53,7 @@ fn populate_bit_valves(input: &str) {
             },
         );
     }
+    Ok(())
 }
 
 static BIT_VALVES: Lazy<Mutex<BTreeMap<u64, Valve<u64>>>> =
@@ -101,15 +114,16 @@ impl FromStr for Valve<String> {
     type Err = anyhow::Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let words: Vec<&str> = s.split_whitespace().collect();
+        if words.len() < 10 {
+            bail!("expected \"Valve <name> has flow rate=<rate>; tunnels lead to valves <names>\"");
+        }
         let name: String = words[1].into();
         let rate = words[4]
-            .split_once("=")
-            .unwrap()
-            .1
-            .strip_suffix(";")
-            .unwrap()
+            .strip_prefix("rate=")
+            .and_then(|r| r.strip_suffix(';'))
+            .context("expected \"rate=<rate>;\"")?
             .parse()
-            .unwrap();
+            .context("invalid flow rate")?;
         let conns: Vec<String> = words[9..]
             .iter()
             .map(|w| w.trim_end_matches(",").into())
@@ -152,7 +166,7 @@ fn score(state: u64) -> u64 {
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 2022, 16);
+    let input = &advent_of_code::read_input(2022, 16);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -164,12 +178,12 @@ mod tests {
     #[test]
     fn test_part_one() {
         let input = advent_of_code::read_file("examples", 2022, 16);
-        assert_eq!(part_one(&input), Some(1651));
+        assert_eq!(part_one(&input).unwrap(), 1651);
     }
 
     #[test]
     fn test_part_two() {
         let input = advent_of_code::read_file("examples", 2022, 16);
-        assert_eq!(part_two(&input), Some(1707));
+        assert_eq!(part_two(&input).unwrap(), 1707);
     }
 }
@@ -1,15 +1,247 @@
-use advent_of_code::{run_solution, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::registry::Entry;
+use advent_of_code::results::{DayRun, DayStatus};
+use advent_of_code::{
+    build_solutions, print_day_header, print_day_run, print_day_status, run_executable, RunOptions,
+    ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, DEFAULT_TIMEOUT,
+};
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
 
-fn main() {
-    let total: f64 = (2015..=2022)
-        .map(|year| {
-            let sub_total: f64 = (1..=25).map(|day| run_solution(year, day)).sum();
-            sub_total
-        })
-        .sum();
+advent_of_code::solutions!();
+
+struct Options {
+    year: Option<u16>,
+    days: Option<Vec<u8>>,
+    only_solved: bool,
+    jobs: usize,
+    timeout: Duration,
+    /// Runs every day as its own process. Always the case with more than one job.
+    isolated: bool,
+}
 
+fn parse_args() -> Result<Options, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let jobs = args.opt_value_from_str("--jobs")?.unwrap_or(1).max(1);
+    Ok(Options {
+        year: args.opt_value_from_str("--year")?,
+        days: args.opt_value_from_fn("--days", advent_of_code::parse_days)?,
+        only_solved: args.contains("--only-solved"),
+        jobs,
+        timeout: args
+            .opt_value_from_str("--timeout")?
+            .map_or(DEFAULT_TIMEOUT, Duration::from_secs),
+        // output of in-process days cannot be told apart once they run at the same time,
+        // so concurrent days always run as their own process, which captures it.
+        isolated: args.contains("--isolated") || jobs > 1,
+    })
+}
+
+fn print_total(label: &str, total: f64, contended: bool) {
     println!(
-        "{}Total:{} {}{:.2}ms{}",
-        ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, total, ANSI_RESET
+        "{}{}:{} {}{:.2}ms{}{}",
+        ANSI_BOLD,
+        label,
+        ANSI_RESET,
+        ANSI_ITALIC,
+        total,
+        if contended { " (contended)" } else { "" },
+        ANSI_RESET
     );
 }
+
+/// Executables of the days to run as their own process, built up front by `build_solutions`.
+type Executables = HashMap<(u16, u8), PathBuf>;
+
+/// Runs a day against its input, or returns `None` if there is no input.
+fn run_day(entry: &'static Entry, options: &Options, executables: &Executables) -> Option<DayRun> {
+    let input = advent_of_code::read_puzzle_input(entry.year, entry.day)?;
+    let executable = match options.isolated {
+        true => executables.get(&(entry.year, entry.day))?,
+        false => return Some(entry.run_guarded(input, options.timeout)),
+    };
+
+    Some(
+        run_executable(
+            entry.year,
+            entry.day,
+            executable,
+            &RunOptions {
+                timeout: options.timeout,
+                ..RunOptions::default()
+            },
+        )
+        .unwrap_or_else(|e| DayRun {
+            status: DayStatus::Crashed,
+            results: vec![],
+            stdout: String::new(),
+            stderr: format!("{:#}", e),
+        }),
+    )
+}
+
+/// Runs `entries` on up to `options.jobs` threads and calls `on_day` with the results in the order of `entries`.
+fn run_days(
+    entries: &[&'static Entry],
+    options: &Options,
+    executables: &Executables,
+    mut on_day: impl FnMut(&Entry, Option<DayRun>),
+) {
+    let jobs = options.jobs;
+    if jobs == 1 {
+        entries
+            .iter()
+            .for_each(|entry| on_day(entry, run_day(entry, options, executables)));
+        return;
+    }
+
+    let next = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(entries.len()) {
+            let tx = tx.clone();
+            let next = &next;
+            scope.spawn(move || loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                match entries.get(index) {
+                    Some(entry) => tx
+                        .send((index, run_day(entry, options, executables)))
+                        .unwrap(),
+                    None => break,
+                }
+            });
+        }
+        drop(tx);
+
+        // days finish out of order, hold them back until all previous days are printed.
+        let mut pending = BTreeMap::new();
+        let mut printed = 0;
+        for (index, results) in rx {
+            pending.insert(index, results);
+            while let Some(results) = pending.remove(&printed) {
+                on_day(entries[printed], results);
+                printed += 1;
+            }
+        }
+    });
+}
+
+/// Prints the results of a day, returning its total time in ms.
+fn print_day(entry: &Entry, run: Option<DayRun>, options: &Options) -> f64 {
+    let run = match run {
+        Some(run) => run,
+        None => {
+            if !options.only_solved {
+                print_day_header(entry.day);
+                println!("No input.");
+            }
+            return 0_f64;
+        }
+    };
+
+    if options.only_solved && run.status != DayStatus::Solved {
+        return 0_f64;
+    }
+    match options.isolated {
+        true => print_day_run(entry.day, &run),
+        false => {
+            print_day_header(entry.day);
+            entry.print_results(&run.results);
+            print_day_status(&run);
+        }
+    }
+    run.results
+        .iter()
+        .fold(0_f64, |total, r| total + r.millis())
+}
+
+/// Builds the days of `entries` that have an input, exiting if any of them fails to build.
+fn build_days(entries: &[&'static Entry]) -> Executables {
+    let days: Vec<(u16, u8)> = entries
+        .iter()
+        .map(|e| (e.year, e.day))
+        .filter(|&(year, day)| advent_of_code::read_puzzle_input(year, day).is_some())
+        .collect();
+    match build_solutions(&days) {
+        Ok(executables) => days.into_iter().zip(executables).collect(),
+        Err(e) => {
+            eprintln!("Failed to build solutions: {:#}", e);
+            process::exit(1);
+        }
+    }
+}
+
+fn main() {
+    let options = match parse_args() {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    // `SOLUTIONS` holds every `src/bin/<year>_<day>.rs`, sorted by year and day.
+    let entries: Vec<&'static Entry> = SOLUTIONS
+        .iter()
+        .filter(|e| options.year.is_none_or(|y| e.year == y))
+        .filter(|e| options.days.as_ref().is_none_or(|d| d.contains(&e.day)))
+        .collect();
+
+    // build every day once up front, so jobs do not each wait for their own `cargo build`.
+    let executables = match options.isolated {
+        true => build_days(&entries),
+        false => Executables::new(),
+    };
+
+    let contended = options.jobs > 1 && entries.len() > 1;
+    if contended {
+        println!(
+            "Running {} days at a time, timings may be inflated by contention.",
+            options.jobs
+        );
+    }
+
+    let mut total = 0_f64;
+    let mut failures = 0;
+    let mut year_total: Option<(u16, f64)> = None;
+    run_days(&entries, &options, &executables, |entry, run| {
+        match year_total {
+            Some((year, _)) if year == entry.year => {}
+            previous => {
+                if let Some((year, sub_total)) = previous {
+                    print_total(&format!("{} Total", year), sub_total, contended);
+                }
+                println!("🎄 {}{}{} 🎄", ANSI_BOLD, entry.year, ANSI_RESET);
+                year_total = Some((entry.year, 0_f64));
+            }
+        }
+
+        if run.as_ref().is_some_and(|r| r.status.is_failure()) {
+            failures += 1;
+        }
+        let millis = print_day(entry, run, &options);
+        if let Some((_, sub_total)) = year_total.as_mut() {
+            *sub_total += millis;
+        }
+        total += millis;
+    });
+
+    if let Some((year, sub_total)) = year_total {
+        print_total(&format!("{} Total", year), sub_total, contended);
+    }
+    print_total("Total", total, contended);
+
+    if failures > 0 {
+        println!("---");
+        println!("{} day(s) crashed or timed out.", failures);
+        process::exit(1);
+    }
+}
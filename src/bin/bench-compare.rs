@@ -0,0 +1,77 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::history;
+use advent_of_code::{ANSI_BOLD, ANSI_RESET};
+use std::process;
+use std::time::Duration;
+
+struct Options {
+    threshold: f64,
+    baseline: Option<String>,
+}
+
+fn parse_args() -> Result<Options, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Options {
+        threshold: args.opt_value_from_str("--threshold")?.unwrap_or(10_f64),
+        baseline: args.opt_value_from_str("--baseline")?,
+    })
+}
+
+fn main() {
+    let options = match parse_args() {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let entries = match history::load() {
+        Ok(entries) => entries,
+        Err(e) => {
+            eprintln!("Failed to load benchmark history: {:#}", e);
+            process::exit(1);
+        }
+    };
+
+    let comparisons = history::compare(&entries, options.baseline.as_deref());
+    if comparisons.is_empty() {
+        println!("No benchmarks to compare. Run `cargo solve <year> <day> --bench` on two commits first.");
+        return;
+    }
+
+    let mut regressions = 0;
+    for comparison in comparisons.iter() {
+        let regressed = comparison.is_regression(options.threshold);
+        if regressed {
+            regressions += 1;
+        }
+        println!(
+            "{}{} Day {} Part {}: {:.2?} ({}) -> {:.2?} ({}) {:+.1}%{}{}",
+            if regressed { ANSI_BOLD } else { "" },
+            comparison.year,
+            comparison.day,
+            comparison.part,
+            Duration::from_nanos(comparison.baseline.stats.median),
+            comparison.baseline.commit,
+            Duration::from_nanos(comparison.current.stats.median),
+            comparison.current.commit,
+            comparison.change(),
+            if regressed { " ⚠️  slower" } else { "" },
+            ANSI_RESET
+        );
+    }
+
+    println!("---");
+    if regressions > 0 {
+        println!(
+            "{} part(s) got more than {}% slower.",
+            regressions, options.threshold
+        );
+        process::exit(1);
+    }
+    println!("🎄 No part got more than {}% slower.", options.threshold);
+}
@@ -2,133 +2,167 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use std::io::Write;
-use std::path::PathBuf;
-use std::{env::temp_dir, io, process::Command};
-use std::{fs, process};
-
-fn remove_file(path: &PathBuf) {
-    #[allow(unused_must_use)]
-    {
-        fs::remove_file(path);
-    }
-}
-
-fn exit_with_status(status: i32, path: &PathBuf) -> ! {
-    remove_file(path);
-    process::exit(status);
+use advent_of_code::answers::Answers;
+use advent_of_code::aoc::{self, Client};
+use advent_of_code::cache::{Kind, Manifest};
+use advent_of_code::{puzzle, write_atomic};
+use anyhow::Result;
+use std::fs;
+use std::process;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Time between two requests to the website, to go easy on it when downloading many days.
+const REQUEST_DELAY: Duration = Duration::from_secs(3);
+
+struct Options {
+    /// Downloads every unlocked day of this year instead of a single day.
+    all: Option<u16>,
+    /// Downloads again even if the cache is up to date.
+    force: bool,
 }
 
-fn main() {
-    // acquire a temp file path to write aoc-cli output to.
-    // aoc-cli expects this file not to be present - delete just in case.
-    let mut tmp_file_path = temp_dir();
-    tmp_file_path.push("aoc_input_tmp");
-    remove_file(&tmp_file_path);
-
-    let args = match advent_of_code::parse_args() {
-        Ok(args) => args,
-        Err(e) => {
-            eprintln!("Failed to process arguments: {}", e);
-            exit_with_status(1, &tmp_file_path);
+fn parse_args() -> Result<(Vec<(u16, u8)>, Options), pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let options = Options {
+        all: args.opt_value_from_str("--all")?,
+        force: args.contains("--force"),
+    };
+    let days = match options.all {
+        Some(year) => (1..=25)
+            .take_while(|&day| aoc::is_unlocked(year, day))
+            .map(|day| (year, day))
+            .collect(),
+        None => {
+            let args = advent_of_code::parse_day_args(&mut args)?;
+            vec![(args.year, args.day)]
         }
     };
+    Ok((days, options))
+}
 
-    let day_padded = format!("{:02}", args.day);
-    let suffix = format!("{}_{}", args.year, day_padded);
-    let inputs_path = format!("src/inputs/{}.txt", suffix);
-    let puzzles_path = format!("src/puzzles/{}.md", suffix);
+struct Downloader {
+    client: Option<Client>,
+    manifest: Manifest,
+    last_request: Option<Instant>,
+    force: bool,
+}
 
-    // check if aoc binary exists and is callable.
-    if Command::new("aoc").arg("-V").output().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        exit_with_status(1, &tmp_file_path);
+impl Downloader {
+    /// Downloads a file of a day, waiting for `REQUEST_DELAY` since the previous request.
+    fn fetch(&mut self, year: u16, day: u8, kind: Kind) -> Result<String> {
+        if let Some(wait) = self
+            .last_request
+            .and_then(|last| REQUEST_DELAY.checked_sub(last.elapsed()))
+        {
+            thread::sleep(wait);
+        }
+        let client = match self.client.take() {
+            Some(client) => client,
+            None => Client::from_env()?,
+        };
+
+        println!("Downloading {} for {} day {}...", kind.name(), year, day);
+        let contents = match kind {
+            Kind::Input => client.fetch_input(year, day),
+            Kind::Puzzle => client.fetch_puzzle(year, day),
+        };
+        self.client = Some(client);
+        self.last_request = Some(Instant::now());
+
+        let contents = contents?;
+        self.manifest.store(year, day, kind, &contents)?;
+        self.manifest.save()?;
+        Ok(contents)
     }
 
-    let mut download_args = vec![];
-
-    download_args.append(&mut vec![
-        "download".into(),
-        "--year".into(),
-        args.year.to_string(),
-        "--day".into(),
-        args.day.to_string(),
-        "--input-file".into(),
-        tmp_file_path.to_string_lossy().to_string(),
-    ]);
-
-    println!("Downloading input with > aoc {}", download_args.join(" "));
-
-    match Command::new("aoc").args(download_args).output() {
-        Ok(cmd_output) => {
-            io::stdout()
-                .write_all(&cmd_output.stdout)
-                .expect("could not write cmd stdout to pipe.");
-            io::stderr()
-                .write_all(&cmd_output.stderr)
-                .expect("could not write cmd stderr to pipe.");
-            if !cmd_output.status.success() {
-                exit_with_status(1, &tmp_file_path);
+    /// Whether the cached file is out of date. Inputs never change, puzzle descriptions
+    /// gain part two once part one was solved.
+    fn is_stale(&self, year: u16, day: u8, kind: Kind) -> bool {
+        match (kind, self.manifest.get(year, day, kind)) {
+            (Kind::Puzzle, Some(entry)) if !entry.part_two_unlocked => {
+                Answers::load(year, day).is_ok_and(|answers| answers.confirmed(1).is_some())
             }
-        }
-        Err(e) => {
-            eprintln!("failed to spawn aoc-cli: {}", e);
-            exit_with_status(1, &tmp_file_path);
+            _ => false,
         }
     }
 
-    match fs::copy(&tmp_file_path, &inputs_path) {
-        Ok(_) => {
-            println!("---");
-            println!("🎄 Successfully wrote input to \"{}\".", &inputs_path);
-        }
-        Err(e) => {
-            eprintln!("could not copy downloaded input to input file: {}", e);
-            exit_with_status(1, &tmp_file_path);
+    /// Puts the file of a day in place, downloading it unless the cache is up to date.
+    fn download(&mut self, year: u16, day: u8, kind: Kind) -> Result<()> {
+        let cached = match self.force || self.is_stale(year, day, kind) {
+            true => None,
+            false => self.manifest.read(year, day, kind),
+        };
+        let contents = match cached {
+            Some(contents) => contents,
+            None => self.fetch(year, day, kind)?,
+        };
+
+        let path = kind.target_path(year, day);
+        if fs::read_to_string(&path).ok().as_ref() != Some(&contents) {
+            write_atomic(&path, &contents)?;
+            println!("🎄 Successfully wrote {} to {:?}.", kind.name(), &path);
         }
+        Ok(())
     }
 
-    remove_file(&tmp_file_path);
-    let mut puzzle_args = vec![];
-
-    puzzle_args.append(&mut vec![
-        "read".into(),
-        "--year".into(),
-        args.year.to_string(),
-        "--day".into(),
-        args.day.to_string(),
-        "--puzzle-file".into(),
-        tmp_file_path.to_string_lossy().to_string(),
-    ]);
-
-    println!("Downloading puzzle with > aoc {}", puzzle_args.join(" "));
-
-    match Command::new("aoc").args(puzzle_args).output() {
-        Ok(cmd_output) => {
-            io::stdout()
-                .write_all(&cmd_output.stdout)
-                .expect("could not write cmd stdout to pipe.");
-            io::stderr()
-                .write_all(&cmd_output.stderr)
-                .expect("could not write cmd stderr to pipe.");
-            if !cmd_output.status.success() {
-                exit_with_status(1, &tmp_file_path);
-            }
+    fn download_day(&mut self, year: u16, day: u8) -> Result<()> {
+        self.download(year, day, Kind::Input)?;
+        self.download(year, day, Kind::Puzzle)?;
+
+        // fill in the example, and the answer of part two once it is unlocked.
+        let update = puzzle::update_examples(year, day)?;
+        if let Some(path) = update.example {
+            println!("🎄 Successfully wrote example to {:?}.", path);
         }
-        Err(e) => {
-            eprintln!("failed to spawn aoc-cli: {}", e);
-            exit_with_status(1, &tmp_file_path);
+        for (part, answer) in update.answers {
+            println!(
+                "🎄 Part {} of the example is expected to be {}.",
+                part, answer
+            );
         }
+        Ok(())
     }
+}
 
-    match fs::copy(&tmp_file_path, &puzzles_path) {
-        Ok(_) => {
-            println!("---");
-            println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzles_path);
+fn main() {
+    let (days, options) = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
         }
+    };
+
+    let manifest = match Manifest::load() {
+        Ok(manifest) => manifest,
         Err(e) => {
-            eprintln!("could not copy downloaded puzzle to puzzle file: {}", e);
-            exit_with_status(1, &tmp_file_path);
+            eprintln!("Failed to load download cache: {:#}", e);
+            process::exit(1);
+        }
+    };
+    let mut downloader = Downloader {
+        client: None,
+        manifest,
+        last_request: None,
+        force: options.force,
+    };
+
+    let mut failures = 0;
+    for (year, day) in days {
+        if let Err(e) = downloader.download_day(year, day) {
+            eprintln!("Failed to download {} day {}: {:#}", year, day, e);
+            failures += 1;
         }
     }
+
+    println!("---");
+    if failures > 0 {
+        println!("{} day(s) could not be downloaded.", failures);
+        process::exit(1);
+    }
+    match options.all {
+        Some(year) => println!("🎄 Every unlocked day of {} is downloaded.", year),
+        None => println!("🎄 Input and puzzle are up to date."),
+    }
 }
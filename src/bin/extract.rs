@@ -0,0 +1,38 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::puzzle;
+use std::process;
+
+fn main() {
+    let args = match advent_of_code::parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let update = match puzzle::update_examples(args.year, args.day) {
+        Ok(update) => update,
+        Err(e) => {
+            eprintln!("Failed to extract examples: {:#}", e);
+            process::exit(1);
+        }
+    };
+
+    println!("---");
+    if let Some(path) = &update.example {
+        println!("🎄 Wrote example input to {:?}.", path);
+    }
+    for (part, answer) in &update.answers {
+        println!(
+            "🎄 Part {} of the example is expected to be {}.",
+            part, answer
+        );
+    }
+    if update.example.is_none() && update.answers.is_empty() {
+        println!("🎄 Examples are up to date.");
+    }
+}
@@ -0,0 +1,57 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::puzzle;
+use advent_of_code::Args;
+use std::fs;
+use std::process;
+
+fn parse_args() -> Result<(Args, Option<u8>), pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let part = args.opt_value_from_str("--part")?;
+    Ok((advent_of_code::parse_day_args(&mut args)?, part))
+}
+
+fn main() {
+    let (args, part) = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let path = puzzle::puzzle_path(args.year, args.day);
+    let markdown = match fs::read_to_string(&path) {
+        Ok(markdown) if !markdown.trim().is_empty() => markdown,
+        Ok(_) | Err(_) => {
+            eprintln!(
+                "No puzzle found at {:?}. Try running `cargo download {} {}` first.",
+                path, args.year, args.day
+            );
+            process::exit(1);
+        }
+    };
+
+    let (part_one, part_two) = puzzle::split_parts(&markdown);
+    let section = match part {
+        None => Some(markdown.as_str()),
+        Some(1) => Some(part_one),
+        Some(2) => part_two,
+        Some(part) => {
+            eprintln!("Failed to process arguments: there is no part {}", part);
+            process::exit(1);
+        }
+    };
+    match section {
+        Some(section) => print!("{}", puzzle::render(section)),
+        None => {
+            eprintln!(
+                "Part two is not unlocked yet. Solve part one and run `cargo download {} {}` again.",
+                args.year, args.day
+            );
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,116 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::answers::{Answers, Check};
+use advent_of_code::history;
+use advent_of_code::readme::{render_table, replace_table, DaySummary, PartSummary};
+use advent_of_code::results::PartResult;
+use advent_of_code::DEFAULT_TIMEOUT;
+use std::fs;
+use std::process;
+use std::time::Duration;
+
+advent_of_code::solutions!();
+
+fn main() {
+    let mut args = pico_args::Arguments::from_env();
+    let cached = args.contains("--cached");
+    let timeout = match args.opt_value_from_str("--timeout") {
+        Ok(timeout) => timeout.map_or(DEFAULT_TIMEOUT, Duration::from_secs),
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let history = match history::load() {
+        Ok(history) => history,
+        Err(e) => {
+            eprintln!("Failed to load benchmark history: {:#}", e);
+            process::exit(1);
+        }
+    };
+
+    let days: Vec<DaySummary> = SOLUTIONS
+        .iter()
+        .map(|entry| {
+            let answers = match Answers::load(entry.year, entry.day) {
+                Ok(answers) => answers,
+                Err(e) => {
+                    eprintln!("{:#}", e);
+                    process::exit(1);
+                }
+            };
+            let input = match cached {
+                true => None,
+                false => advent_of_code::read_puzzle_input(entry.year, entry.day),
+            };
+            // `None` if the day was not run. Parts that crashed or timed out are missing.
+            let results: Option<Vec<PartResult>> =
+                input.map(|input| entry.run_guarded(input, timeout).results);
+
+            let mut parts = [PartSummary::default(); 2];
+            for (part, summary) in (1..=2).zip(parts.iter_mut()) {
+                // latest benchmark median, used if the day is not run.
+                let benchmarked = history
+                    .iter()
+                    .rev()
+                    .find(|h| h.year == entry.year && h.day == entry.day && h.part == part)
+                    .map(|h| h.stats.median);
+
+                *summary = match results.as_ref().map(|r| r.iter().find(|r| r.part == part)) {
+                    // only an answer that matches the confirmed one earns a star.
+                    Some(Some(result)) => PartSummary {
+                        solved: result
+                            .answer
+                            .as_deref()
+                            .is_some_and(|answer| answers.check(part, answer) == Check::Confirmed),
+                        nanos: Some(result.nanos),
+                    },
+                    // the part crashed or timed out, so the code does not produce an answer.
+                    Some(None) => PartSummary::default(),
+                    // without input or with `--cached`, fall back to what was recorded.
+                    None => PartSummary {
+                        solved: answers.confirmed(part).is_some(),
+                        nanos: benchmarked,
+                    },
+                };
+            }
+
+            DaySummary {
+                year: entry.year,
+                day: entry.day,
+                parts,
+            }
+        })
+        .collect();
+
+    let readme_path = advent_of_code::project_root().join("README.md");
+    let readme = match fs::read_to_string(&readme_path) {
+        Ok(readme) => readme,
+        Err(e) => {
+            eprintln!("Failed to read README.md: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let updated = match replace_table(&readme, &render_table(&days)) {
+        Some(updated) => updated,
+        None => {
+            eprintln!("README.md does not contain a table block to replace.");
+            process::exit(1);
+        }
+    };
+
+    match fs::write(&readme_path, updated) {
+        Ok(_) => {
+            println!("---");
+            println!("🎄 Updated the results table in \"README.md\".");
+        }
+        Err(e) => {
+            eprintln!("Failed to write README.md: {}", e);
+            process::exit(1);
+        }
+    }
+}
@@ -2,102 +2,258 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use advent_of_code::examples::{example_path, specs_path, DEFAULT_EXAMPLE};
+use advent_of_code::puzzle;
+use advent_of_code::templates::{self, day_vars, DEFAULT_TEMPLATE};
+use advent_of_code::Args;
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
+    fs::{self, File, OpenOptions},
+    io::{ErrorKind, Write},
+    path::Path,
     process,
 };
 
-const MODULE_TEMPLATE: &str = r###"pub fn part_one(input: &str) -> Option<u32> {
-    None
+/// Start of the tests module, regenerated by `--tests-only`. Other `#[cfg(test)]` items are
+/// part of the solution.
+const TESTS_MARKER: &str = "#[cfg(test)]\nmod tests";
+
+/// Template of `src/examples/<year>_<day>.toml`.
+const SPECS_TEMPLATE: &str = "examples.toml.tmpl";
+
+fn safe_create_file(path: &Path) -> Result<File, std::io::Error> {
+    OpenOptions::new().write(true).create_new(true).open(path)
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    None
+/// Creates a file with `contents`, leaving existing files untouched.
+fn create_placeholder(path: &Path, contents: &str, label: &str) {
+    if path.exists() {
+        return;
+    }
+    match safe_create_file(path).and_then(|mut file| file.write_all(contents.as_bytes())) {
+        Ok(_) => {
+            println!("Created {} {:?}", label, path);
+        }
+        Err(e) => {
+            eprintln!("Failed to create {}: {}", label, e);
+            process::exit(1);
+        }
+    }
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", YEAR, DAY);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+struct Options {
+    template: String,
+    example: Option<String>,
+    force: bool,
+    tests_only: bool,
+    download: bool,
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+fn parse_args() -> Result<(Args, Options), pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let options = Options {
+        template: args
+            .opt_value_from_str("--template")?
+            .unwrap_or_else(|| DEFAULT_TEMPLATE.to_string()),
+        example: args.opt_value_from_str("--example")?,
+        force: args.contains("--force"),
+        tests_only: args.contains("--tests-only"),
+        download: args.contains("--download"),
+    };
+    Ok((advent_of_code::parse_day_args(&mut args)?, options))
+}
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", YEAR, DAY);
-        assert_eq!(part_one(&input), None);
+/// Adds an empty example called `name` to an existing day.
+fn create_named_example(args: &Args, name: &str) {
+    let example_path = example_path(args.year, args.day, name);
+    match safe_create_file(&example_path) {
+        Ok(_) => {
+            println!("Created empty example file {:?}", &example_path);
+        }
+        Err(e) => {
+            eprintln!("Failed to create example file: {}", e);
+            process::exit(1);
+        }
     }
 
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", YEAR, DAY);
-        assert_eq!(part_two(&input), None);
-    }
+    println!("---");
+    println!(
+        "🎄 Declare its expected answers in a `[{}]` table of {:?}.",
+        name,
+        specs_path(args.year, args.day)
+    );
 }
-"###;
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create_new(true).open(path)
+/// Loads and renders the template `file_name`, which is a solution template if `solution` is set.
+fn render_template(args: &Args, file_name: &str, solution: bool) -> String {
+    let template = match solution {
+        true => templates::load_solution(file_name),
+        false => templates::load(file_name),
+    };
+    match template {
+        Ok(template) => templates::render(&template, &day_vars(args.year, args.day)),
+        Err(e) => {
+            eprintln!("Failed to load template: {:#}", e);
+            process::exit(1);
+        }
+    }
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+/// Where the tests module starts in `contents`, or why it cannot be told apart from the rest.
+fn find_tests(contents: &str) -> Result<usize, String> {
+    let starts: Vec<usize> = contents
+        .match_indices(TESTS_MARKER)
+        .map(|(i, _)| i)
+        .collect();
+    match starts[..] {
+        [start] => Ok(start),
+        [] => Err("no tests module".into()),
+        _ => Err(format!("{} tests modules", starts.len())),
+    }
 }
 
-fn main() {
-    let args = match advent_of_code::parse_args() {
-        Ok(day) => day,
+/// Replaces the tests module of an existing solution with the one of the template.
+fn regenerate_tests(args: &Args, module_path: &Path, template: &str) {
+    let module = match fs::read_to_string(module_path) {
+        Ok(module) => module,
         Err(e) => {
-            eprintln!("Failed to process arguments: {}", e);
+            eprintln!("Failed to read module file: {}", e);
             process::exit(1);
         }
     };
 
-    let suffix = format!("{}_{:02}", args.year, args.day);
-
-    let example_path = format!("src/examples/{}.txt", suffix);
-    let module_path = format!("src/bin/{}.rs", suffix);
-
-    let mut file = match safe_create_file(&module_path) {
-        Ok(file) => file,
+    let template = render_template(args, template, true);
+    let tests = match find_tests(&template) {
+        Ok(index) => &template[index..],
         Err(e) => {
-            eprintln!("Failed to create module file: {}", e);
+            eprintln!(
+                "Failed to find the tests module of the template: found {}.",
+                e
+            );
+            process::exit(1);
+        }
+    };
+    // the solution is everything before the tests module, so it must be found exactly.
+    let solution = match find_tests(&module) {
+        Ok(index) => &module[..index],
+        Err(e) => {
+            eprintln!(
+                "Failed to find the tests module in {:?}: found {}, expected a single `#[cfg(test)] mod tests`. Update the tests by hand.",
+                module_path, e
+            );
             process::exit(1);
         }
     };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("DAY", &args.day.to_string())
-            .replace("YEAR", &args.year.to_string())
-            .as_bytes(),
-    ) {
+    match fs::write(module_path, format!("{}{}", solution, tests)) {
         Ok(_) => {
-            println!("Created module file \"{}\"", &module_path);
+            println!("Regenerated tests in {:?}", module_path);
         }
         Err(e) => {
             eprintln!("Failed to write module contents: {}", e);
             process::exit(1);
         }
     }
+}
 
-    match create_file(&example_path) {
+fn write_module(args: &Args, module_path: &Path, options: &Options) {
+    let module = render_template(args, &options.template, true);
+    let file = match options.force {
+        true => File::create(module_path),
+        false => safe_create_file(module_path),
+    };
+    let written = file.and_then(|mut file| file.write_all(module.as_bytes()));
+    match written {
         Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+            println!("Created module file {:?}", module_path);
+        }
+        Err(e) if e.kind() == ErrorKind::AlreadyExists => {
+            eprintln!(
+                "Module file {:?} already exists. Pass --force to overwrite it or --tests-only to regenerate its tests.",
+                module_path
+            );
+            process::exit(1);
         }
         Err(e) => {
-            eprintln!("Failed to create example file: {}", e);
+            eprintln!("Failed to create module file: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+fn download(args: &Args) {
+    let status = process::Command::new("cargo")
+        .args(["run", "--quiet", "--bin", "download", "--"])
+        .args([args.year.to_string(), args.day.to_string()])
+        .status();
+    match status {
+        Ok(status) if status.success() => {}
+        Ok(_) => {
+            eprintln!("Failed to download input and puzzle.");
+            process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("Failed to download input and puzzle: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+fn main() {
+    let (args, options) = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
             process::exit(1);
         }
+    };
+
+    if let Some(name) = &options.example {
+        create_named_example(&args, name);
+        return;
+    }
+
+    let suffix = format!("{}_{:02}", args.year, args.day);
+    let module_path = advent_of_code::project_root()
+        .join("src")
+        .join("bin")
+        .join(format!("{}.rs", suffix));
+
+    if options.tests_only {
+        regenerate_tests(&args, &module_path, &options.template);
+        return;
+    }
+
+    write_module(&args, &module_path, &options);
+
+    create_placeholder(
+        &advent_of_code::file_path("inputs", args.year, args.day),
+        "",
+        "empty input file",
+    );
+    create_placeholder(
+        &puzzle::puzzle_path(args.year, args.day),
+        "",
+        "empty puzzle file",
+    );
+    create_placeholder(
+        &example_path(args.year, args.day, DEFAULT_EXAMPLE),
+        "",
+        "empty example file",
+    );
+    create_placeholder(
+        &specs_path(args.year, args.day),
+        &render_template(&args, SPECS_TEMPLATE, false),
+        "example answers file",
+    );
+
+    if options.download {
+        download(&args);
     }
 
     println!("---");
+    // `build.rs` picks up every `src/bin/<year>_<day>.rs`, so the day is part of `cargo all` from now on.
     println!(
-        "🎄 Type `cargo solve {} {}` to run your solution.", 
+        "🎄 Type `cargo solve {} {}` to run your solution.",
         args.year, args.day
     );
 }
@@ -0,0 +1,170 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::answers::{Answers, Check};
+use advent_of_code::registry::Entry;
+use advent_of_code::results::DayStatus;
+use advent_of_code::{ANSI_BOLD, ANSI_RESET, DEFAULT_TIMEOUT};
+use std::process;
+use std::time::Duration;
+
+advent_of_code::solutions!();
+
+enum Status {
+    Pass,
+    Fail(String),
+    Unverified,
+    MissingInput,
+    Crashed,
+    TimedOut(Duration),
+}
+
+impl Status {
+    fn label(&self) -> String {
+        match self {
+            Status::Pass => "✔ pass".into(),
+            Status::Fail(expected) => format!("✖ FAIL (expected {})", expected),
+            Status::Unverified => "? no answer recorded".into(),
+            Status::MissingInput => "- missing input".into(),
+            Status::Crashed => "✖ CRASHED".into(),
+            Status::TimedOut(timeout) => format!("✖ TIMED OUT (after {}s)", timeout.as_secs()),
+        }
+    }
+}
+
+fn verify(answers: &Answers, part: u8, answer: Option<&str>) -> Status {
+    match (answers.confirmed(part), answer) {
+        (None, _) => Status::Unverified,
+        (Some(expected), None) => Status::Fail(expected.to_string()),
+        (Some(expected), Some(answer)) => match answers.check(part, answer) {
+            Check::Confirmed => Status::Pass,
+            _ => Status::Fail(expected.to_string()),
+        },
+    }
+}
+
+/// Runs both parts of a day, so that a panicking or hanging day only fails its own row.
+/// Returns the panic message if the day crashed.
+fn verify_day(
+    entry: &'static Entry,
+    answers: &Answers,
+    input: String,
+    timeout: Duration,
+) -> (Vec<Status>, Option<String>) {
+    let run = entry.run_guarded(input, timeout);
+    let statuses = [1, 2]
+        .into_iter()
+        .map(|part| match run.results.iter().find(|r| r.part == part) {
+            Some(result) => verify(answers, part, result.answer.as_deref()),
+            None => match run.status {
+                DayStatus::TimedOut(timeout) => Status::TimedOut(timeout),
+                _ => Status::Crashed,
+            },
+        })
+        .collect();
+    let crash = (run.status == DayStatus::Crashed).then_some(run.stderr);
+    (statuses, crash)
+}
+
+fn row(cells: [&str; 4]) -> String {
+    format!("{:<6}{:<5}{:<32}{}", cells[0], cells[1], cells[2], cells[3])
+}
+
+struct Options {
+    year: Option<u16>,
+    day: Option<u8>,
+    timeout: Duration,
+}
+
+fn parse_args() -> Result<Options, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let timeout = args
+        .opt_value_from_str("--timeout")?
+        .map_or(DEFAULT_TIMEOUT, Duration::from_secs);
+    Ok(Options {
+        year: args.opt_free_from_str()?,
+        day: args.opt_free_from_str()?,
+        timeout,
+    })
+}
+
+fn main() {
+    let options = match parse_args() {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let mut rows: Vec<(u16, u8, Vec<Status>)> = vec![];
+    let mut crashes: Vec<(u16, u8, String)> = vec![];
+    for entry in SOLUTIONS.iter().filter(|e| {
+        options.year.is_none_or(|y| e.year == y) && options.day.is_none_or(|d| e.day == d)
+    }) {
+        let answers = match Answers::load(entry.year, entry.day) {
+            Ok(answers) => answers,
+            Err(e) => {
+                eprintln!("{:#}", e);
+                process::exit(1);
+            }
+        };
+        let statuses = match advent_of_code::read_puzzle_input(entry.year, entry.day) {
+            Some(input) => {
+                let (statuses, crash) = verify_day(entry, &answers, input, options.timeout);
+                if let Some(message) = crash {
+                    crashes.push((entry.year, entry.day, message));
+                }
+                statuses
+            }
+            None => vec![Status::MissingInput, Status::MissingInput],
+        };
+        rows.push((entry.year, entry.day, statuses));
+    }
+
+    println!(
+        "{}{}{}",
+        ANSI_BOLD,
+        row(["Year", "Day", "Part 1", "Part 2"]),
+        ANSI_RESET
+    );
+    for (year, day, statuses) in rows.iter() {
+        println!(
+            "{}",
+            row([
+                &year.to_string(),
+                &day.to_string(),
+                &statuses[0].label(),
+                &statuses[1].label(),
+            ])
+        );
+    }
+
+    for (year, day, message) in crashes.iter() {
+        eprintln!("{} day {} crashed: {}", year, day, message);
+    }
+
+    let count = |f: fn(&Status) -> bool| {
+        rows.iter()
+            .flat_map(|(_, _, statuses)| statuses)
+            .filter(|s| f(s))
+            .count()
+    };
+    let failures = count(|s| matches!(s, Status::Fail(_)));
+    let broken = count(|s| matches!(s, Status::Crashed | Status::TimedOut(_)));
+    println!("---");
+    if failures > 0 {
+        println!(
+            "{} part(s) no longer produce their confirmed answer.",
+            failures
+        );
+    }
+    if broken > 0 {
+        println!("{} part(s) crashed or timed out.", broken);
+    }
+    if failures > 0 || broken > 0 {
+        process::exit(1);
+    }
+    println!("🎄 No regressions.");
+}
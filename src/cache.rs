@@ -0,0 +1,167 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::puzzle::PART_TWO_MARKER;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Kind {
+    Input,
+    Puzzle,
+}
+
+impl Kind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Kind::Input => "input",
+            Kind::Puzzle => "puzzle",
+        }
+    }
+
+    /// Where the downloaded file is used: `src/inputs/<year>_<day>.txt` or `src/puzzles/<year>_<day>.md`.
+    pub fn target_path(&self, year: u16, day: u8) -> PathBuf {
+        match self {
+            Kind::Input => crate::file_path("inputs", year, day),
+            Kind::Puzzle => crate::puzzle::puzzle_path(year, day),
+        }
+    }
+}
+
+/// A downloaded file, as listed in `.aoc/cache/manifest.toml`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CacheEntry {
+    pub year: u16,
+    pub day: u8,
+    pub kind: Kind,
+    /// Seconds since the unix epoch.
+    pub fetched_at: u64,
+    /// `content_hash` of the cached file, to detect a damaged cache.
+    pub hash: String,
+    /// Whether the puzzle description includes part two. Always `false` for inputs.
+    #[serde(default)]
+    pub part_two_unlocked: bool,
+}
+
+/// Files downloaded from the website, so they are not fetched again. Inputs never change,
+/// puzzle descriptions only do when part two unlocks.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Manifest {
+    #[serde(default, rename = "entry")]
+    pub entries: Vec<CacheEntry>,
+}
+
+pub fn cache_dir() -> PathBuf {
+    crate::project_root().join(".aoc").join("cache")
+}
+
+pub fn manifest_path() -> PathBuf {
+    cache_dir().join("manifest.toml")
+}
+
+pub fn cached_path(year: u16, day: u8, kind: Kind) -> PathBuf {
+    let extension = match kind {
+        Kind::Input => "txt",
+        Kind::Puzzle => "md",
+    };
+    cache_dir().join(format!("{}_{:02}.{}.{}", year, day, kind.name(), extension))
+}
+
+/// 64-bit FNV-1a hash of `contents` in hex, which unlike `std`'s hasher is stable across releases.
+pub fn content_hash(contents: &str) -> String {
+    let hash = contents.bytes().fold(0xcbf29ce484222325_u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{:016x}", hash)
+}
+
+impl Manifest {
+    pub fn load() -> Result<Self> {
+        let path = manifest_path();
+        match fs::read_to_string(&path) {
+            Ok(contents) => {
+                toml::from_str(&contents).with_context(|| format!("could not parse {:?}", path))
+            }
+            Err(_) => Ok(Manifest::default()),
+        }
+    }
+
+    pub fn save(&self) -> Result<()> {
+        crate::write_atomic(&manifest_path(), &toml::to_string(self)?)
+    }
+
+    pub fn get(&self, year: u16, day: u8, kind: Kind) -> Option<&CacheEntry> {
+        self.entries
+            .iter()
+            .find(|e| (e.year, e.day, e.kind) == (year, day, kind))
+    }
+
+    /// The cached contents of a file, unless it was never downloaded or its cache file is damaged.
+    pub fn read(&self, year: u16, day: u8, kind: Kind) -> Option<String> {
+        let entry = self.get(year, day, kind)?;
+        let contents = fs::read_to_string(cached_path(year, day, kind)).ok()?;
+        (content_hash(&contents) == entry.hash).then_some(contents)
+    }
+
+    /// Lists `contents` as the latest download of a file.
+    pub fn record(&mut self, year: u16, day: u8, kind: Kind, contents: &str) {
+        let entry = CacheEntry {
+            year,
+            day,
+            kind,
+            fetched_at: crate::unix_time(),
+            hash: content_hash(contents),
+            part_two_unlocked: kind == Kind::Puzzle && contents.contains(PART_TWO_MARKER),
+        };
+        self.entries
+            .retain(|e| (e.year, e.day, e.kind) != (year, day, kind));
+        self.entries.push(entry);
+        self.entries.sort_by_key(|e| (e.year, e.day, e.kind.name()));
+    }
+
+    /// Writes `contents` to the cache and lists it, see `record`.
+    pub fn store(&mut self, year: u16, day: u8, kind: Kind, contents: &str) -> Result<()> {
+        crate::write_atomic(&cached_path(year, day, kind), contents)?;
+        self.record(year, day, kind, contents);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manifest() {
+        assert_eq!(content_hash(""), "cbf29ce484222325");
+        assert_eq!(content_hash("a"), "af63dc4c8601ec8c");
+
+        let mut manifest = Manifest::default();
+        manifest.record(2022, 2, Kind::Puzzle, "## --- Day 2 ---");
+        manifest.record(2022, 1, Kind::Input, "1000\n");
+        manifest.record(
+            2022,
+            2,
+            Kind::Puzzle,
+            "## --- Day 2 ---\n## --- Part Two ---",
+        );
+
+        assert_eq!(manifest.entries.len(), 2);
+        assert_eq!(manifest.entries[0].kind, Kind::Input);
+        assert!(!manifest.entries[0].part_two_unlocked);
+        assert!(
+            manifest
+                .get(2022, 2, Kind::Puzzle)
+                .unwrap()
+                .part_two_unlocked
+        );
+        assert_eq!(manifest.get(2022, 2, Kind::Input), None);
+
+        let parsed: Manifest = toml::from_str(&toml::to_string(&manifest).unwrap()).unwrap();
+        assert_eq!(parsed, manifest);
+    }
+}
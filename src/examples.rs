@@ -0,0 +1,286 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::params::{with_params, Table};
+use crate::results::{Answer, IntoAnswer};
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+/// Name of the `src/examples/<year>_<day>.txt` example, as opposed to named ones
+/// such as `src/examples/<year>_<day>.larger.txt`.
+pub const DEFAULT_EXAMPLE: &str = "default";
+
+/// What is declared about an example in `src/examples/<year>_<day>.toml`, in a table named after it:
+///
+/// ```toml
+/// [default]
+/// part_one = 26
+/// part_two = 56000011
+///
+/// [default.params]
+/// row = 10
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct ExampleSpec {
+    pub part_one: Option<toml::Value>,
+    pub part_two: Option<toml::Value>,
+    /// Puzzle parameters that differ from the real input, see `params`.
+    #[serde(default)]
+    pub params: Table,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    pub spec: ExampleSpec,
+}
+
+/// Key of the expected answer of `part` in an example table.
+fn answer_key(part: u8) -> &'static str {
+    match part {
+        1 => "part_one",
+        _ => "part_two",
+    }
+}
+
+impl Example {
+    /// The answer `part` is expected to give for this example, if declared.
+    pub fn expected(&self, part: u8) -> Option<String> {
+        let value = match part {
+            1 => self.spec.part_one.as_ref(),
+            _ => self.spec.part_two.as_ref(),
+        }?;
+        Some(match value {
+            toml::Value::String(s) => s.clone(),
+            value => value.to_string(),
+        })
+    }
+}
+
+/// Path of the example called `name`, see `DEFAULT_EXAMPLE`.
+pub fn example_path(year: u16, day: u8, name: &str) -> PathBuf {
+    let file_name = match name {
+        DEFAULT_EXAMPLE => format!("{}_{:02}.txt", year, day),
+        name => format!("{}_{:02}.{}.txt", year, day, name),
+    };
+    crate::data_dir().join("examples").join(file_name)
+}
+
+/// Reads the example called `name`, like `read_file("examples", ..)` does for the default one.
+pub fn read(year: u16, day: u8, name: &str) -> Result<String> {
+    let path = example_path(year, day, name);
+    fs::read_to_string(&path).with_context(|| format!("could not read {:?}", path))
+}
+
+pub fn specs_path(year: u16, day: u8) -> PathBuf {
+    crate::data_dir()
+        .join("examples")
+        .join(format!("{}_{:02}.toml", year, day))
+}
+
+/// Parses the example name out of an example file name of the given day.
+fn example_name(file_name: &str, year: u16, day: u8) -> Option<String> {
+    let rest = file_name.strip_prefix(&format!("{}_{:02}", year, day))?;
+    match rest.strip_suffix(".txt")? {
+        "" => Some(DEFAULT_EXAMPLE.to_string()),
+        name => Some(name.strip_prefix('.')?.to_string()),
+    }
+}
+
+fn parse_specs(contents: &str) -> Result<BTreeMap<String, ExampleSpec>> {
+    Ok(toml::from_str(contents)?)
+}
+
+/// Declares `answers` (of part one and two) for the example `name` in the contents of a
+/// `.toml` specs file. Answers that are already declared are kept, and so is the rest of
+/// the file, comments included. Returns the new contents and the answers it declared.
+pub fn declare_answers(
+    contents: &str,
+    name: &str,
+    answers: &[Option<String>; 2],
+) -> Result<(String, Vec<(u8, String)>)> {
+    let spec = parse_specs(contents)?.remove(name).unwrap_or_default();
+    let declared: Vec<(u8, String)> = [(1, &spec.part_one), (2, &spec.part_two)]
+        .into_iter()
+        .zip(answers)
+        .filter_map(|((part, current), answer)| match current {
+            Some(_) => None,
+            None => Some((part, answer.clone()?)),
+        })
+        .collect();
+    if declared.is_empty() {
+        return Ok((contents.to_string(), declared));
+    }
+
+    let lines: String = declared
+        .iter()
+        .map(|(part, answer)| {
+            let value = match answer.parse::<i64>() {
+                Ok(n) => toml::Value::Integer(n),
+                Err(_) => toml::Value::String(answer.clone()),
+            };
+            format!("{} = {}\n", answer_key(*part), value)
+        })
+        .collect();
+
+    let header = format!("[{}]", name);
+    let mut out = String::with_capacity(contents.len() + lines.len());
+    let mut inserted = false;
+    for line in contents.split_inclusive('\n') {
+        out.push_str(line);
+        if !inserted && line.trim() == header {
+            if !line.ends_with('\n') {
+                out.push('\n');
+            }
+            out.push_str(&lines);
+            inserted = true;
+        }
+    }
+    if !inserted {
+        // the table goes first, as it may be followed by a `[<name>.params]` subtable.
+        let separator = if out.is_empty() { "" } else { "\n" };
+        out = format!("{}\n{}{}{}", header, lines, separator, out);
+    }
+
+    parse_specs(&out)?;
+    Ok((out, declared))
+}
+
+/// Loads all examples of a day, default example first.
+pub fn load(year: u16, day: u8) -> Result<Vec<Example>> {
+    let specs_path = specs_path(year, day);
+    let mut specs = match fs::read_to_string(&specs_path) {
+        Ok(contents) => {
+            parse_specs(&contents).with_context(|| format!("invalid {:?}", specs_path))?
+        }
+        Err(_) => BTreeMap::new(),
+    };
+
+    let dir = crate::data_dir().join("examples");
+    let mut names: Vec<String> = fs::read_dir(&dir)
+        .with_context(|| format!("could not read {:?}", dir))?
+        .filter_map(|entry| example_name(entry.ok()?.file_name().to_str()?, year, day))
+        .collect();
+    names.sort_by_key(|name| (name != DEFAULT_EXAMPLE, name.clone()));
+
+    names
+        .into_iter()
+        .map(|name| {
+            let input = read(year, day, &name)?;
+            Ok(Example {
+                spec: specs.remove(&name).unwrap_or_default(),
+                name,
+                input,
+            })
+        })
+        .collect()
+}
+
+/// Runs `solver` with the parameters of every example that declares an expected answer
+/// for `part`, and panics with the examples it got wrong. Also panics if no example declares
+/// an answer for `part`, so a test never passes without checking anything.
+pub fn check<T: IntoAnswer>(year: u16, day: u8, part: u8, solver: impl Fn(&str) -> T) {
+    let examples = load(year, day).unwrap_or_else(|e| panic!("{:#}", e));
+
+    let checked: Vec<(&Example, String)> = examples
+        .iter()
+        .filter_map(|example| Some((example, example.expected(part)?)))
+        .collect();
+    assert!(
+        !checked.is_empty(),
+        "no example declares an expected answer for part {}, add `{} = <answer>` to {:?}",
+        part,
+        answer_key(part),
+        specs_path(year, day)
+    );
+
+    let failures: Vec<String> = checked
+        .into_iter()
+        .filter_map(|(example, expected)| {
+            match with_params(&example.spec.params, || solver(&example.input)).into_answer() {
+                Answer::Solved(answer) if answer == expected => None,
+                answer => Some(format!(
+                    "example \"{}\": expected {}, got {:?}",
+                    example.name, expected, answer
+                )),
+            }
+        })
+        .collect();
+
+    assert!(
+        failures.is_empty(),
+        "part {} is wrong for {} example(s):\n{}",
+        part,
+        failures.len(),
+        failures.join("\n")
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_specs() {
+        assert_eq!(
+            example_name("2022_09.txt", 2022, 9),
+            Some(DEFAULT_EXAMPLE.into())
+        );
+        assert_eq!(
+            example_name("2022_09.larger.txt", 2022, 9),
+            Some("larger".into())
+        );
+        assert_eq!(example_name("2022_09.toml", 2022, 9), None);
+        assert_eq!(example_name("2022_10.txt", 2022, 9), None);
+
+        let mut specs = parse_specs(
+            "[default]\npart_one = 13\n\n[default.params]\nrow = 10\n\n[larger]\npart_two = \"CMZ\"\n",
+        )
+            .unwrap()
+            .into_iter()
+            .map(|(name, spec)| Example {
+                name,
+                input: String::new(),
+                spec,
+            });
+        let default = specs.next().unwrap();
+        assert_eq!(
+            (default.expected(1), default.expected(2)),
+            (Some("13".into()), None)
+        );
+        assert_eq!(default.spec.params["row"], toml::Value::Integer(10));
+        assert_eq!(specs.next().unwrap().expected(2), Some("CMZ".into()));
+    }
+
+    #[test]
+    fn test_declare_answers() {
+        let scaffolded = "# answers\n[default]\n# part_one = 0\n";
+        let (contents, declared) =
+            declare_answers(scaffolded, DEFAULT_EXAMPLE, &[Some("24000".into()), None]).unwrap();
+        assert_eq!(
+            contents,
+            "# answers\n[default]\npart_one = 24000\n# part_one = 0\n"
+        );
+        assert_eq!(declared, vec![(1, "24000".into())]);
+
+        let answers = [Some("1".into()), Some("CMZ".into())];
+        let (contents, declared) = declare_answers(&contents, DEFAULT_EXAMPLE, &answers).unwrap();
+        assert_eq!(
+            contents,
+            "# answers\n[default]\npart_two = \"CMZ\"\npart_one = 24000\n# part_one = 0\n"
+        );
+        assert_eq!(declared, vec![(2, "CMZ".into())]);
+
+        let (contents, _) =
+            declare_answers("[default.params]\nrow = 10\n", DEFAULT_EXAMPLE, &answers).unwrap();
+        assert_eq!(
+            contents,
+            "[default]\npart_one = 1\npart_two = \"CMZ\"\n\n[default.params]\nrow = 10\n"
+        );
+    }
+}
@@ -2,3 +2,129 @@
  * Use this file if you want to extract helpers from your solutions.
  * Example import from this file: `use advent_of_code::helpers::example_fn;`.
  */
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+mod grid;
+mod point;
+mod sparse;
+
+pub use grid::Grid;
+pub use point::{Coord, Dir4, Dir8, Point2};
+pub use sparse::SparseGrid;
+
+/// An error while parsing puzzle input, pointing at the offending text of a line.
+///
+/// Displays as the line with a caret under the offending text:
+///
+/// ```text
+/// line 3, column 3: expected a number of steps
+///   |
+/// 3 | R 4x
+///   |   ^^
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line number, if known. See `ParseError::on_line`.
+    pub line: Option<usize>,
+    /// 1-based column of the offending text, in characters.
+    pub column: usize,
+    /// Width of the offending text, in characters.
+    pub width: usize,
+    pub expected: String,
+    pub text: String,
+}
+
+impl ParseError {
+    /// Creates an error pointing at `token`, which must be a slice of `line`.
+    pub fn new(line: &str, token: &str, expected: impl Into<String>) -> Self {
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(line.as_ptr() as usize)
+            .filter(|&offset| offset + token.len() <= line.len())
+            .expect("token must be a slice of line");
+        ParseError {
+            line: None,
+            column: line[..offset].chars().count() + 1,
+            width: token.chars().count().max(1),
+            expected: expected.into(),
+            text: line.to_string(),
+        }
+    }
+
+    /// Creates an error pointing just past the end of `line`, for text that is missing.
+    pub fn at_end(line: &str, expected: impl Into<String>) -> Self {
+        ParseError::new(line, &line[line.len()..], expected)
+    }
+
+    /// Sets the line number, for errors of parsers that only see a single line.
+    pub fn on_line(self, line: usize) -> Self {
+        ParseError {
+            line: Some(line),
+            ..self
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let number = self.line.map_or(String::new(), |l| l.to_string());
+        let gutter = " ".repeat(number.len());
+        if let Some(line) = self.line {
+            write!(f, "line {}, ", line)?;
+        }
+        writeln!(f, "column {}: expected {}", self.column, self.expected)?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", number, self.text)?;
+        write!(
+            f,
+            "{} | {}{}",
+            gutter,
+            " ".repeat(self.column - 1),
+            "^".repeat(self.width)
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `token`, a slice of `line`, or points at it with `expected` if that fails.
+pub fn parse_token<T: FromStr>(line: &str, token: &str, expected: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::new(line, token, expected))
+}
+
+/// Parses every line of `input`, adding the line number to errors.
+pub fn parse_lines<T>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr<Err = ParseError>,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| line.parse().map_err(|e: ParseError| e.on_line(i + 1)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let line = "Sensor at x=2, y=1x";
+        let error = parse_token::<i64>(line, &line[17..], "a number").unwrap_err();
+        assert_eq!((error.column, error.width), (18, 2));
+        assert_eq!(
+            error.on_line(12).to_string(),
+            "line 12, column 18: expected a number\n   |\n12 | Sensor at x=2, y=1x\n   |                  ^^"
+        );
+
+        let error = ParseError::at_end("R", "a number of steps");
+        assert_eq!(
+            error.to_string(),
+            "column 2: expected a number of steps\n |\n | R\n |  ^"
+        );
+    }
+}
@@ -0,0 +1,281 @@
+use super::{Dir4, Dir8, Point2};
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+
+/// A rectangular grid of cells, addressed by `Point2::new(x, y)` with `(0, 0)` in the top left.
+///
+/// Indexing with `grid[pos]` panics outside the grid, `get` doesn't.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// A grid with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// A grid of the given width from cells in row order.
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Self {
+        assert!(
+            width > 0 && cells.len().is_multiple_of(width),
+            "{} cells do not fill rows of {}",
+            cells.len(),
+            width
+        );
+        Grid {
+            height: cells.len() / width,
+            width,
+            cells,
+        }
+    }
+
+    /// Parses a grid with a row per line, mapping each character to a cell with `cell`.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> T) -> Self {
+        let mut width = None;
+        let mut cells = vec![];
+        for (y, line) in input.lines().enumerate() {
+            let len = cells.len();
+            cells.extend(line.chars().map(&mut cell));
+            let row_width = cells.len() - len;
+            match width {
+                None => width = Some(row_width),
+                Some(width) => assert_eq!(
+                    width,
+                    row_width,
+                    "line {} has {} cells, expected {}",
+                    y + 1,
+                    row_width,
+                    width
+                ),
+            }
+        }
+        let width = width.unwrap_or(0);
+        Grid {
+            height: cells.len().checked_div(width).unwrap_or(0),
+            width,
+            cells,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index_of(&self, pos: Point2<usize>) -> Option<usize> {
+        (pos.x < self.width && pos.y < self.height).then_some(pos.y * self.width + pos.x)
+    }
+
+    fn position_of(&self, index: usize) -> Point2<usize> {
+        Point2::new(index % self.width, index / self.width)
+    }
+
+    pub fn get(&self, pos: Point2<usize>) -> Option<&T> {
+        self.cells.get(self.index_of(pos)?)
+    }
+
+    pub fn get_mut(&mut self, pos: Point2<usize>) -> Option<&mut T> {
+        let index = self.index_of(pos)?;
+        self.cells.get_mut(index)
+    }
+
+    /// `pos` moved by `offset`, if that is still inside the grid.
+    pub fn offset(&self, pos: Point2<usize>, offset: Point2<isize>) -> Option<Point2<usize>> {
+        let x = pos.x.checked_add_signed(offset.x)?;
+        let y = pos.y.checked_add_signed(offset.y)?;
+        let pos = Point2::new(x, y);
+        self.index_of(pos).map(|_| pos)
+    }
+
+    /// Positions of all cells in row order.
+    pub fn positions(&self) -> impl Iterator<Item = Point2<usize>> + '_ {
+        (0..self.cells.len()).map(|i| self.position_of(i))
+    }
+
+    /// All cells in row order.
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// All cells with their positions in row order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point2<usize>, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on a width of 0, which only an empty grid has.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} is outside the grid", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// Positions from next to `pos` in direction `dir` up to the edge of the grid.
+    pub fn ray(
+        &self,
+        pos: Point2<usize>,
+        dir: impl Into<Dir8>,
+    ) -> impl Iterator<Item = Point2<usize>> + '_ {
+        let offset = dir.into().offset();
+        std::iter::successors(self.offset(pos, offset), move |&p| self.offset(p, offset))
+    }
+
+    /// Positions of the up to 4 orthogonal neighbours of `pos`.
+    pub fn neighbours4(&self, pos: Point2<usize>) -> impl Iterator<Item = Point2<usize>> + '_ {
+        Dir4::ALL
+            .iter()
+            .filter_map(move |dir| self.offset(pos, dir.offset()))
+    }
+
+    /// Positions of the up to 8 neighbours of `pos`, diagonals included.
+    pub fn neighbours8(&self, pos: Point2<usize>) -> impl Iterator<Item = Point2<usize>> + '_ {
+        Dir8::ALL
+            .iter()
+            .filter_map(move |dir| self.offset(pos, dir.offset()))
+    }
+
+    /// Position of the first cell equal to `value` in row order.
+    pub fn find(&self, value: &T) -> Option<Point2<usize>>
+    where
+        T: PartialEq,
+    {
+        let index = self.cells.iter().position(|cell| cell == value)?;
+        Some(self.position_of(index))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// The grid mirrored along its diagonal, so rows become columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.remap(self.height, self.width, |p| Point2::new(p.y, p.x))
+    }
+
+    /// The grid rotated a quarter turn clockwise.
+    pub fn rotate_cw(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.remap(height, self.width, |p| Point2::new(p.y, height - 1 - p.x))
+    }
+
+    /// The grid rotated a quarter turn counterclockwise.
+    pub fn rotate_ccw(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.remap(self.height, width, |p| Point2::new(width - 1 - p.y, p.x))
+    }
+
+    /// A `width` by `height` grid whose cell at `pos` is the cell of this grid at `source(pos)`.
+    fn remap(
+        &self,
+        width: usize,
+        height: usize,
+        source: impl Fn(Point2<usize>) -> Point2<usize>,
+    ) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..width * height)
+            .map(|i| self[source(Point2::new(i % width, i / width))].clone())
+            .collect();
+        Grid {
+            cells,
+            width,
+            height,
+        }
+    }
+}
+
+impl<T> Index<Point2<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Point2<usize>) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{} is outside the grid", pos))
+    }
+}
+
+impl<T> IndexMut<Point2<usize>> for Grid<T> {
+    fn index_mut(&mut self, pos: Point2<usize>) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{} is outside the grid", pos))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid() {
+        let grid = Grid::parse("123\n456", |c| c.to_digit(10).unwrap());
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point2::new(2, 1)], 6);
+        assert_eq!(grid.get(Point2::new(3, 0)), None);
+        assert_eq!(grid.find(&5), Some(Point2::new(1, 1)));
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.column(1).collect::<Vec<_>>(), vec![&2, &5]);
+        assert_eq!(
+            grid.ray(Point2::new(0, 0), Dir8::Right).collect::<Vec<_>>(),
+            vec![Point2::new(1, 0), Point2::new(2, 0)]
+        );
+        assert_eq!(
+            grid.ray(Point2::new(0, 0), Dir4::Down).collect::<Vec<_>>(),
+            vec![Point2::new(0, 1)]
+        );
+        assert_eq!(grid.neighbours4(Point2::new(0, 0)).count(), 2);
+        assert_eq!(grid.neighbours8(Point2::new(1, 0)).count(), 5);
+
+        assert_eq!(grid.to_string(), "123\n456");
+        assert_eq!(grid.transpose().to_string(), "14\n25\n36");
+        assert_eq!(grid.rotate_cw().to_string(), "41\n52\n63");
+        assert_eq!(grid.rotate_ccw().to_string(), "36\n25\n14");
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+    }
+}
@@ -0,0 +1,305 @@
+use super::ParseError;
+use std::fmt::{self, Display};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+/// Signed integers that `Point2` can measure distances and turn with.
+pub trait Coord:
+    Copy + Ord + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn abs(self) -> Self;
+    fn signum(self) -> Self;
+}
+
+macro_rules! impl_coord {
+    ($($t:ty),*) => {$(
+        impl Coord for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn abs(self) -> Self {
+                <$t>::abs(self)
+            }
+
+            fn signum(self) -> Self {
+                <$t>::signum(self)
+            }
+        }
+    )*};
+}
+
+impl_coord!(i8, i16, i32, i64, i128, isize);
+
+/// A point or vector in 2D, with `y` pointing down as in puzzle input unless a puzzle says otherwise.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Point2 { x, y }
+    }
+}
+
+impl<T: Coord> Point2<T> {
+    pub fn manhattan(self, other: Self) -> T {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// Distance when diagonal steps count as one, as for a king in chess.
+    pub fn chebyshev(self, other: Self) -> T {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// The vector with each component replaced by its sign, i.e. a step towards it.
+    pub fn signum(self) -> Self {
+        Point2::new(self.x.signum(), self.y.signum())
+    }
+
+    /// The vector turned a quarter clockwise, e.g. up `(0, -1)` to right `(1, 0)`.
+    pub fn rotate_cw(self) -> Self {
+        Point2::new(-self.y, self.x)
+    }
+
+    /// The vector turned a quarter counterclockwise, e.g. up `(0, -1)` to left `(-1, 0)`.
+    pub fn rotate_ccw(self) -> Self {
+        Point2::new(self.y, -self.x)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point2<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Point2::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point2<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Point2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point2<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point2<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+/// Scales a vector, e.g. a direction by a number of steps.
+impl<T: Mul<Output = T> + Copy> Mul<T> for Point2<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self {
+        Point2::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point2<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Point2::new(-self.x, -self.y)
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Point2::new(x, y)
+    }
+}
+
+impl<T> From<Point2<T>> for (T, T) {
+    fn from(p: Point2<T>) -> Self {
+        (p.x, p.y)
+    }
+}
+
+impl<T: Display> Display for Point2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/// One of the 4 orthogonal directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir4 {
+    /// All directions, clockwise from up.
+    pub const ALL: [Dir4; 4] = [Dir4::Up, Dir4::Right, Dir4::Down, Dir4::Left];
+
+    /// The step in this direction, with `y` pointing down.
+    pub fn offset<T: Coord>(self) -> Point2<T> {
+        let (zero, one) = (T::ZERO, T::ONE);
+        match self {
+            Dir4::Up => Point2::new(zero, -one),
+            Dir4::Right => Point2::new(one, zero),
+            Dir4::Down => Point2::new(zero, one),
+            Dir4::Left => Point2::new(-one, zero),
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        Dir4::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Dir4::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Dir4::ALL[(self as usize + 2) % 4]
+    }
+
+    /// Parses `U`/`R`/`D`/`L`, `N`/`E`/`S`/`W` or `^`/`>`/`v`/`<`.
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            'U' | 'N' | '^' => Some(Dir4::Up),
+            'R' | 'E' | '>' => Some(Dir4::Right),
+            'D' | 'S' | 'v' => Some(Dir4::Down),
+            'L' | 'W' | '<' => Some(Dir4::Left),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for Dir4 {
+    type Err = ParseError;
+
+    /// Parses a single character of `from_char`. The error only shows `s` itself; within a line
+    /// of input, parse with `parse_token` so the error points into that line.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next().and_then(Dir4::from_char), chars.next()) {
+            (Some(dir), None) => Ok(dir),
+            _ => Err(ParseError::new(s, s, "a direction (U, R, D or L)")),
+        }
+    }
+}
+
+/// One of the 8 directions, diagonals included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Dir8 {
+    /// All directions, clockwise from up.
+    pub const ALL: [Dir8; 8] = [
+        Dir8::Up,
+        Dir8::UpRight,
+        Dir8::Right,
+        Dir8::DownRight,
+        Dir8::Down,
+        Dir8::DownLeft,
+        Dir8::Left,
+        Dir8::UpLeft,
+    ];
+
+    /// The step in this direction, with `y` pointing down.
+    pub fn offset<T: Coord>(self) -> Point2<T> {
+        let (up, right, down, left) = (
+            Dir4::Up.offset(),
+            Dir4::Right.offset(),
+            Dir4::Down.offset(),
+            Dir4::Left.offset(),
+        );
+        match self {
+            Dir8::Up => up,
+            Dir8::UpRight => up + right,
+            Dir8::Right => right,
+            Dir8::DownRight => down + right,
+            Dir8::Down => down,
+            Dir8::DownLeft => down + left,
+            Dir8::Left => left,
+            Dir8::UpLeft => up + left,
+        }
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        Dir8::ALL[dir as usize * 2]
+    }
+}
+
+impl FromStr for Dir8 {
+    type Err = ParseError;
+
+    /// Parses one or two letters of `Dir4::from_char`, e.g. `U`, `UR` or `NE`. As for `Dir4`,
+    /// use `parse_token` within a line of input.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let dirs: Option<Vec<Dir4>> = s.chars().map(Dir4::from_char).collect();
+        let dir = match dirs.as_deref() {
+            Some(&[dir]) => Some(dir.into()),
+            Some(&[vertical, horizontal]) => match (vertical, horizontal) {
+                (Dir4::Up, Dir4::Right) => Some(Dir8::UpRight),
+                (Dir4::Down, Dir4::Right) => Some(Dir8::DownRight),
+                (Dir4::Down, Dir4::Left) => Some(Dir8::DownLeft),
+                (Dir4::Up, Dir4::Left) => Some(Dir8::UpLeft),
+                _ => None,
+            },
+            _ => None,
+        };
+        dir.ok_or_else(|| ParseError::new(s, s, "a direction such as U, UR or NE"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point() {
+        let a = Point2::new(1, -2);
+        let b = Point2::from((4, 2));
+        assert_eq!(a + b, Point2::new(5, 0));
+        assert_eq!(b - a, Point2::new(3, 4));
+        assert_eq!(a * 3, Point2::new(3, -6));
+        assert_eq!((a.manhattan(b), a.chebyshev(b)), (7, 4));
+        assert_eq!((b - a).signum(), Point2::new(1, 1));
+        assert_eq!(<(i32, i32)>::from(-a), (-1, 2));
+
+        let up: Point2<i64> = Dir4::Up.offset();
+        assert_eq!(up.rotate_cw(), Dir4::Right.offset());
+        assert_eq!(up.rotate_ccw(), Dir4::Left.offset());
+        assert_eq!(Dir4::Left.turn_right(), Dir4::Up);
+        assert_eq!(Dir4::Up.turn_left(), Dir4::Left);
+        assert_eq!(Dir4::Down.opposite(), Dir4::Up);
+
+        assert_eq!("R".parse::<Dir4>(), Ok(Dir4::Right));
+        assert!("X".parse::<Dir4>().is_err());
+        assert_eq!("NE".parse::<Dir8>(), Ok(Dir8::UpRight));
+        assert_eq!("D".parse::<Dir8>(), Ok(Dir8::Down));
+        assert_eq!(Dir8::DownLeft.offset(), Point2::new(-1, 1));
+    }
+}
@@ -0,0 +1,156 @@
+use super::Point2;
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+use std::ops::Index;
+
+/// An unbounded grid that only stores the cells that were set, for maps that grow as a
+/// puzzle runs. Unset cells read as a default value.
+///
+/// The bounds grow to include every cell that is set, so they never need to be guessed up front.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point2<i64>, T>,
+    default: T,
+    /// Top left and bottom right corner of the set cells, if any.
+    bounds: Option<(Point2<i64>, Point2<i64>)>,
+}
+
+impl<T> SparseGrid<T> {
+    /// An empty grid whose unset cells read as `default`.
+    pub fn new(default: T) -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+            default,
+            bounds: None,
+        }
+    }
+
+    /// The cell at `pos`, or the default value if it was never set.
+    pub fn get(&self, pos: Point2<i64>) -> &T {
+        self.cells.get(&pos).unwrap_or(&self.default)
+    }
+
+    /// The cell at `pos`, set to the default value first if it was never set.
+    pub fn get_mut(&mut self, pos: Point2<i64>) -> &mut T
+    where
+        T: Clone,
+    {
+        self.grow(pos);
+        self.cells
+            .entry(pos)
+            .or_insert_with(|| self.default.clone())
+    }
+
+    /// Sets the cell at `pos`, returning its previous value if it was set.
+    pub fn insert(&mut self, pos: Point2<i64>, value: T) -> Option<T> {
+        self.grow(pos);
+        self.cells.insert(pos, value)
+    }
+
+    /// Whether the cell at `pos` was set, even if to the default value.
+    pub fn contains(&self, pos: Point2<i64>) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    /// Number of set cells.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    fn grow(&mut self, pos: Point2<i64>) {
+        self.bounds = Some(match self.bounds {
+            None => (pos, pos),
+            Some((min, max)) => (
+                Point2::new(min.x.min(pos.x), min.y.min(pos.y)),
+                Point2::new(max.x.max(pos.x), max.y.max(pos.y)),
+            ),
+        });
+    }
+
+    /// Top left and bottom right corner, both inclusive, of the smallest box around the set
+    /// cells, or `None` if no cell was set.
+    pub fn bounds(&self) -> Option<(Point2<i64>, Point2<i64>)> {
+        self.bounds
+    }
+
+    /// Whether `pos` lies in the box of `bounds`.
+    pub fn in_bounds(&self, pos: Point2<i64>) -> bool {
+        self.bounds.is_some_and(|(min, max)| {
+            (min.x..=max.x).contains(&pos.x) && (min.y..=max.y).contains(&pos.y)
+        })
+    }
+
+    /// Positions of the set cells in no particular order.
+    pub fn positions(&self) -> impl Iterator<Item = Point2<i64>> + '_ {
+        self.cells.keys().copied()
+    }
+
+    /// The set cells in no particular order.
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.values()
+    }
+
+    /// The set cells with their positions in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point2<i64>, &T)> {
+        self.cells.iter().map(|(&pos, value)| (pos, value))
+    }
+}
+
+impl<T> Index<Point2<i64>> for SparseGrid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Point2<i64>) -> &T {
+        self.get(pos)
+    }
+}
+
+/// Prints the box of `bounds` with a row per line, unset cells included. An empty grid prints
+/// nothing.
+impl<T: Display> Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some((min, max)) = self.bounds else {
+            return Ok(());
+        };
+        for y in min.y..=max.y {
+            if y > min.y {
+                writeln!(f)?;
+            }
+            for x in min.x..=max.x {
+                write!(f, "{}", self.get(Point2::new(x, y)))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sparse_grid() {
+        let mut grid = SparseGrid::new('.');
+        assert_eq!((grid.bounds(), grid.to_string()), (None, String::new()));
+
+        grid.insert(Point2::new(2, -1), '#');
+        grid.insert(Point2::new(-1, 1), '#');
+        *grid.get_mut(Point2::new(0, 0)) = 'o';
+        assert_eq!(grid.insert(Point2::new(2, -1), '+'), Some('#'));
+
+        assert_eq!(grid.len(), 3);
+        assert_eq!(grid[Point2::new(0, 0)], 'o');
+        assert_eq!(grid[Point2::new(100, 100)], '.');
+        assert!(!grid.contains(Point2::new(1, 1)));
+        assert_eq!(
+            grid.bounds(),
+            Some((Point2::new(-1, -1), Point2::new(2, 1)))
+        );
+        assert!(grid.in_bounds(Point2::new(1, 1)));
+        assert!(!grid.in_bounds(Point2::new(3, 0)));
+        assert_eq!(grid.to_string(), "...+\n.o..\n#...");
+    }
+}
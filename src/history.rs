@@ -0,0 +1,183 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::bench::BenchStats;
+use crate::results::PartResult;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// A benchmarked part, as appended to `.aoc/bench_history.jsonl`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    /// Short hash of `HEAD`, suffixed with `-dirty` if the tree had uncommitted changes.
+    pub commit: String,
+    /// Seconds since the unix epoch.
+    pub recorded_at: u64,
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub stats: BenchStats,
+}
+
+pub fn history_path() -> PathBuf {
+    crate::project_root()
+        .join(".aoc")
+        .join("bench_history.jsonl")
+}
+
+fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git").args(args).output().ok()?;
+    match output.status.success() {
+        true => Some(String::from_utf8_lossy(&output.stdout).trim().to_string()),
+        false => None,
+    }
+}
+
+pub fn current_commit() -> String {
+    match git(&["rev-parse", "--short", "HEAD"]) {
+        Some(hash) => match git(&["status", "--porcelain"]) {
+            Some(status) if !status.is_empty() => format!("{}-dirty", hash),
+            _ => hash,
+        },
+        None => "unknown".to_string(),
+    }
+}
+
+/// Appends benchmarked `results` of a day to the history file.
+pub fn record(year: u16, day: u8, results: &[PartResult]) -> Result<()> {
+    let commit = current_commit();
+    let recorded_at = crate::unix_time();
+
+    let path = history_path();
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut file = OpenOptions::new()
+        .append(true)
+        .create(true)
+        .open(&path)
+        .with_context(|| format!("could not open {:?}", path))?;
+
+    for result in results {
+        if let Some(stats) = result.bench {
+            let entry = HistoryEntry {
+                commit: commit.clone(),
+                recorded_at,
+                year,
+                day,
+                part: result.part,
+                stats,
+            };
+            writeln!(file, "{}", serde_json::to_string(&entry)?)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn load() -> Result<Vec<HistoryEntry>> {
+    let path = history_path();
+    match fs::read_to_string(&path) {
+        Ok(contents) => contents
+            .lines()
+            .filter(|l| !l.is_empty())
+            .map(|l| {
+                serde_json::from_str(l).with_context(|| format!("invalid entry in {:?}", path))
+            })
+            .collect(),
+        Err(_) => Ok(vec![]),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub baseline: HistoryEntry,
+    pub current: HistoryEntry,
+}
+
+impl Comparison {
+    /// Relative change of the median, in percent. Positive means slower.
+    pub fn change(&self) -> f64 {
+        let base = self.baseline.stats.median.max(1) as f64;
+        (self.current.stats.median as f64 - base) / base * 100_f64
+    }
+
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.change() > threshold
+    }
+}
+
+/// Compares the latest entry of every part against the latest entry from another commit,
+/// or from `baseline` if given.
+pub fn compare(entries: &[HistoryEntry], baseline: Option<&str>) -> Vec<Comparison> {
+    let mut by_part: BTreeMap<(u16, u8, u8), Vec<&HistoryEntry>> = BTreeMap::new();
+    for entry in entries {
+        by_part
+            .entry((entry.year, entry.day, entry.part))
+            .or_default()
+            .push(entry);
+    }
+
+    by_part
+        .into_iter()
+        .filter_map(|((year, day, part), entries)| {
+            let current = *entries.last()?;
+            let baseline = entries.iter().rev().find(|e| match baseline {
+                Some(commit) => e.commit == commit,
+                None => e.commit != current.commit,
+            })?;
+            Some(Comparison {
+                year,
+                day,
+                part,
+                baseline: (*baseline).clone(),
+                current: current.clone(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(commit: &str, day: u8, median: u64) -> HistoryEntry {
+        HistoryEntry {
+            commit: commit.into(),
+            recorded_at: 0,
+            year: 2022,
+            day,
+            part: 1,
+            stats: BenchStats::from_samples(&[median]),
+        }
+    }
+
+    #[test]
+    fn test_compare() {
+        let entries = vec![
+            entry("aaa", 4, 1000),
+            entry("bbb", 4, 1200),
+            entry("ccc", 4, 1100),
+            entry("ccc", 4, 1300),
+            entry("ccc", 15, 500),
+        ];
+
+        let comparisons = compare(&entries, None);
+        assert_eq!(comparisons.len(), 1);
+        assert_eq!(comparisons[0].baseline.commit, "bbb");
+        assert!((comparisons[0].change() - 8.333_333).abs() < 1.0e-6);
+        assert!(comparisons[0].is_regression(5_f64));
+        assert!(!comparisons[0].is_regression(10_f64));
+
+        let comparisons = compare(&entries, Some("aaa"));
+        assert!((comparisons[0].change() - 30_f64).abs() < 1.0e-6);
+    }
+}
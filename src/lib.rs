@@ -3,45 +3,127 @@
  * There is no need to edit this file unless you want to change template functionality.
  * Prefer `./helpers.rs` if you want to extract code from your solutions.
  */
+use anyhow::{bail, Context, Result};
+use std::collections::HashMap;
 use std::env;
 use std::fs;
-use std::process;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::process::{self, ExitStatus, Stdio};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
+pub mod answers;
+pub mod aoc;
+pub mod bench;
+pub mod cache;
+pub mod examples;
 pub mod helpers;
+pub mod history;
+pub mod params;
+pub mod puzzle;
+pub mod readme;
+pub mod registry;
+pub mod results;
+pub mod templates;
+
+use answers::Answers;
+use bench::{BenchConfig, BENCH_ENV};
+pub use params::params;
+use params::PARAMS_ENV;
+use results::{DayRun, DayStatus, PartResult, RESULTS_FILE_ENV};
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
+pub const ANSI_CYAN: &str = "\x1b[36m";
+pub const ANSI_YELLOW: &str = "\x1b[33m";
 
 #[macro_export]
 macro_rules! solve {
     ($part:expr, $solver:ident, $input:expr) => {{
-        use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-        use std::fmt::Display;
-        use std::time::Instant;
-
-        fn print_result<T: Display>(func: impl FnOnce(&str) -> Option<T>, input: &str) {
-            let timer = Instant::now();
-            let result = func(input);
-            let elapsed = timer.elapsed();
-            match result {
-                Some(result) => {
-                    println!(
-                        "{} {}(elapsed: {:.2?}){}",
-                        result, ANSI_ITALIC, elapsed, ANSI_RESET
-                    );
-                }
-                None => {
-                    println!("not solved.")
-                }
+        use advent_of_code::bench::measure;
+        use advent_of_code::results::{emit, IntoAnswer};
+        use advent_of_code::{check_answer, parse_bin_name, print_part_header, print_part_result};
+
+        fn print_result<T: IntoAnswer>(part: u8, func: impl Fn(&str) -> T, input: &str) {
+            let result = measure(part, func, input);
+            let day = option_env!("CARGO_BIN_NAME").and_then(parse_bin_name);
+            print_part_result(day, &result);
+            if let Some((year, day)) = day.filter(|_| !advent_of_code::custom_input()) {
+                check_answer(year, day, &result);
             }
+            emit(&result);
         }
 
-        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
-        print_result($solver, $input);
+        print_part_header($part);
+        print_result($part, $solver, $input);
     }};
 }
 
+pub fn print_day_header(day: u8) {
+    println!("----------");
+    println!("{}| Day {} |{}", ANSI_BOLD, day, ANSI_RESET);
+    println!("----------");
+}
+
+pub fn print_part_header(part: u8) {
+    println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, part, ANSI_RESET);
+}
+
+/// Prints the answer and timing of a part. `day` is the year and day it belongs to, if known,
+/// and is used to point out which part failed.
+pub fn print_part_result(day: Option<(u16, u8)>, result: &PartResult) {
+    if let Some(error) = &result.error {
+        let part = match day {
+            Some((year, day)) => format!("{} day {} part {}", year, day, result.part),
+            None => format!("Part {}", result.part),
+        };
+        println!("{}❌ {} failed:{} {}", ANSI_BOLD, part, ANSI_RESET, error);
+        return;
+    }
+
+    match (&result.answer, &result.bench) {
+        (Some(answer), Some(stats)) => {
+            println!("{} {}({}){}", answer, ANSI_ITALIC, stats, ANSI_RESET);
+        }
+        (Some(answer), None) => {
+            println!(
+                "{} {}(elapsed: {:.2?}){}",
+                answer,
+                ANSI_ITALIC,
+                result.elapsed(),
+                ANSI_RESET
+            );
+        }
+        (None, _) => {
+            println!("not solved.")
+        }
+    }
+}
+
+/// Warns if `result` contradicts the answers recorded in `src/answers`.
+pub fn check_answer(year: u16, day: u8, result: &PartResult) {
+    let answer = match &result.answer {
+        Some(answer) => answer,
+        None => return,
+    };
+    match Answers::load(year, day) {
+        Ok(answers) => {
+            if let Some(warning) = answers.check(result.part, answer).warning() {
+                println!("{}⚠️  Answer {}{}", ANSI_BOLD, warning, ANSI_RESET);
+            }
+        }
+        Err(e) => eprintln!("{:#}", e),
+    }
+}
+
+/// Parses the year and day from a solution binary name such as `2022_01`.
+pub fn parse_bin_name(name: &str) -> Option<(u16, u8)> {
+    let (year, day) = name.split_once('_')?;
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
 pub struct Args {
     pub year: u16,
     pub day: u8,
@@ -49,95 +131,331 @@ pub struct Args {
 
 pub fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
+    parse_day_args(&mut args)
+}
+
+/// Parses a list of days such as `3`, `10-16` or `1,3,5-7`.
+pub fn parse_days(s: &str) -> Result<Vec<u8>, String> {
+    let mut days = vec![];
+    for part in s.split(',') {
+        let (start, end) = part.split_once('-').unwrap_or((part, part));
+        let (start, end): (u8, u8) = match (start.trim().parse(), end.trim().parse()) {
+            (Ok(start), Ok(end)) if start <= end => (start, end),
+            _ => return Err(format!("invalid day range \"{}\"", part)),
+        };
+        days.extend(start..=end);
+    }
+    Ok(days)
+}
+
+/// Parses the positional `<year> <day>` arguments. Call this after extracting any options.
+pub fn parse_day_args(args: &mut pico_args::Arguments) -> Result<Args, pico_args::Error> {
     Ok(Args {
         year: args.free_from_str()?,
         day: args.free_from_str()?,
     })
 }
 
-pub fn read_file(folder: &str, year: u16, day: u8) -> String {
+/// Overrides the directory holding the `inputs`, `examples`, `puzzles` and `answers` folders.
+pub const DATA_DIR_ENV: &str = "AOC_DATA_DIR";
+
+/// Root of the project: the directory of the manifest `cargo` runs, or else the closest
+/// directory containing a `Cargo.toml`, so commands also work from a subdirectory.
+pub fn project_root() -> PathBuf {
+    if let Some(dir) = env::var_os("CARGO_MANIFEST_DIR") {
+        return PathBuf::from(dir);
+    }
     let cwd = env::current_dir().unwrap();
+    cwd.ancestors()
+        .find(|dir| dir.join("Cargo.toml").is_file())
+        .unwrap_or(&cwd)
+        .to_path_buf()
+}
+
+/// `AOC_DATA_DIR` if set, `<project root>/src` otherwise.
+pub fn data_dir() -> PathBuf {
+    match env::var_os(DATA_DIR_ENV) {
+        Some(dir) => PathBuf::from(dir),
+        None => project_root().join("src"),
+    }
+}
 
-    let filepath = cwd
-        .join("src")
+pub fn file_path(folder: &str, year: u16, day: u8) -> PathBuf {
+    data_dir()
         .join(folder)
-        .join(format!("{}_{:02}.txt", year, day));
-
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
-}
-
-fn parse_time(val: &str, postfix: &str) -> f64 {
-    val.split(postfix).next().unwrap().parse().unwrap()
-}
-
-pub fn parse_exec_time(output: &str) -> f64 {
-    output.lines().fold(0_f64, |acc, l| {
-        if !l.contains("elapsed:") {
-            acc
-        } else {
-            let timing = l.split("(elapsed: ").last().unwrap();
-            // use `contains` istd. of `ends_with`: string may contain ANSI escape sequences.
-            // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-            if timing.contains("ns)") {
-                acc // range below rounding precision.
-            } else if timing.contains("µs)") {
-                acc + parse_time(timing, "µs") / 1000_f64
-            } else if timing.contains("ms)") {
-                acc + parse_time(timing, "ms")
-            } else if timing.contains("s)") {
-                acc + parse_time(timing, "s") * 1000_f64
-            } else {
-                acc
-            }
-        }
+        .join(format!("{}_{:02}.txt", year, day))
+}
+
+/// The puzzle input of a day, unless it was not downloaded yet. `cargo scaffold` leaves an
+/// empty input file, which counts as missing.
+pub fn read_puzzle_input(year: u16, day: u8) -> Option<String> {
+    let input = fs::read_to_string(file_path("inputs", year, day)).ok()?;
+    (!input.trim().is_empty()).then_some(input)
+}
+
+/// Writes `contents` to `path` through a temporary file of this process next to it, so that
+/// `path` never holds a partial write and concurrent writers do not clobber each other's files.
+pub fn write_atomic(path: &Path, contents: &str) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).with_context(|| format!("could not create {:?}", dir))?;
+    }
+    let mut tmp_name = path.file_name().unwrap_or_default().to_os_string();
+    tmp_name.push(format!(".{}.tmp", process::id()));
+    let tmp_path = path.with_file_name(tmp_name);
+
+    fs::write(&tmp_path, contents).with_context(|| format!("could not write {:?}", tmp_path))?;
+    fs::rename(&tmp_path, path).map_err(|e| {
+        let _ = fs::remove_file(&tmp_path);
+        anyhow::Error::new(e).context(format!("could not move {:?} to {:?}", tmp_path, path))
     })
 }
 
-pub fn run_solution(year: u16, day: u8) -> f64 {
-    let day_padded = format!("{:02}", day);
-    let suffix = format!("{}_{}", year, day_padded);
+/// Current time in seconds since the unix epoch, as stored in the answers, history and cache files.
+pub fn unix_time() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
 
-    let cmd = process::Command::new("cargo")
-        .args(["run", "--release", "--bin", &suffix])
-        .output()
-        .unwrap();
+pub fn read_file(folder: &str, year: u16, day: u8) -> String {
+    let path = file_path(folder, year, day);
+    let f = fs::read_to_string(&path);
+    f.unwrap_or_else(|e| panic!("could not open input file {:?}: {}", path, e))
+}
 
-    println!("----------");
-    println!("{}| Day {} |{}", ANSI_BOLD, day, ANSI_RESET);
-    println!("----------");
+/// The `--input` of a solution binary, given as `--input <path>` or `--input=<path>`.
+fn input_arg() -> Option<String> {
+    match pico_args::Arguments::from_env().opt_value_from_str("--input") {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+/// Whether a solution binary was given another input than its puzzle input with `--input`.
+pub fn custom_input() -> bool {
+    input_arg().is_some()
+}
 
-    let output = String::from_utf8(cmd.stdout).unwrap();
-    let is_empty = output.is_empty();
+/// Reads the input of a solution binary: the file passed with `--input <path>`,
+/// stdin for `--input -`, or the day's file in `inputs` otherwise.
+pub fn read_input(year: u16, day: u8) -> String {
+    let input = input_arg();
+    let read = match input.as_deref() {
+        None => return read_file("inputs", year, day),
+        Some("-") => {
+            let mut buf = String::new();
+            io::stdin().read_to_string(&mut buf).map(|_| buf)
+        }
+        Some(path) => fs::read_to_string(path),
+    };
+    read.unwrap_or_else(|e| panic!("could not read input {:?}: {}", input.unwrap(), e))
+}
+
+/// Time a day may take before `run_solution` and `cargo all` give up on it.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Builds the solution binaries of `days` with a single `cargo build` and returns the paths of
+/// their executables, in the order of `days`.
+pub fn build_solutions(days: &[(u16, u8)]) -> Result<Vec<PathBuf>> {
+    if days.is_empty() {
+        return Ok(vec![]);
+    }
+    let bins: Vec<String> = days
+        .iter()
+        .map(|(year, day)| format!("{}_{:02}", year, day))
+        .collect();
+
+    let mut cmd = process::Command::new("cargo");
+    cmd.args([
+        "build",
+        "--release",
+        "--message-format=json-render-diagnostics",
+    ]);
+    for bin in bins.iter() {
+        cmd.args(["--bin", bin]);
+    }
+    let output = cmd.output().context("could not run cargo")?;
+    if !output.status.success() {
+        bail!(
+            "could not build {}:\n{}",
+            bins.join(", "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    let executables: HashMap<String, PathBuf> = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|l| serde_json::from_str::<serde_json::Value>(l).ok())
+        .filter(|m| m["reason"] == "compiler-artifact")
+        .filter_map(|m| {
+            let name = m["target"]["name"].as_str()?.to_string();
+            Some((name, PathBuf::from(m["executable"].as_str()?)))
+        })
+        .collect();
+    bins.iter()
+        .map(|bin| {
+            executables
+                .get(bin)
+                .cloned()
+                .with_context(|| format!("cargo did not report an executable for {}", bin))
+        })
+        .collect()
+}
+
+/// Reads `pipe` to the end on a separate thread, so a chatty child cannot block on a full pipe.
+fn read_to_end(mut pipe: impl Read + Send + 'static) -> thread::JoinHandle<String> {
+    thread::spawn(move || {
+        let mut buf = vec![];
+        #[allow(unused_must_use)]
+        {
+            pipe.read_to_end(&mut buf);
+        }
+        String::from_utf8_lossy(&buf).into_owned()
+    })
+}
 
-    println!(
-        "{}",
-        if is_empty {
-            "Not solved."
-        } else {
-            output.trim()
+/// Waits for `child` to exit, killing it once `timeout` is up.
+fn wait_timeout(child: &mut process::Child, timeout: Duration) -> Result<Option<ExitStatus>> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(Some(status));
+        }
+        if Instant::now() >= deadline {
+            child.kill()?;
+            child.wait()?;
+            return Ok(None);
         }
-    );
+        thread::sleep(Duration::from_millis(10));
+    }
+}
 
-    if is_empty {
-        0_f64
-    } else {
-        parse_exec_time(&output)
+/// How `run_solution` runs a solution binary.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunOptions {
+    /// Benchmarks the parts, see `bench`.
+    pub bench: Option<BenchConfig>,
+    /// Stops the solution after this long. Extended by the time budget of both parts when
+    /// benchmarking; building the binary does not count towards it.
+    pub timeout: Duration,
+    /// Passed on as `--input`, see `read_input`.
+    pub input: Option<String>,
+    /// Passed on as `AOC_PARAMS`, see `params`.
+    pub params: Option<params::Table>,
+}
+
+impl Default for RunOptions {
+    fn default() -> Self {
+        RunOptions {
+            bench: None,
+            timeout: DEFAULT_TIMEOUT,
+            input: None,
+            params: None,
+        }
     }
 }
 
-/// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-#[cfg(test)]
-macro_rules! assert_approx_eq {
-    ($a:expr, $b:expr) => {{
-        let (a, b) = (&$a, &$b);
-        assert!(
-            (*a - *b).abs() < 1.0e-6,
-            "{} is not approximately equal to {}",
-            *a,
-            *b
-        );
-    }};
+/// Builds the solution of a day and runs it as its own process, see `run_executable`.
+pub fn run_solution(year: u16, day: u8, options: &RunOptions) -> Result<DayRun> {
+    let executable = build_solutions(&[(year, day)])?.remove(0);
+    run_executable(year, day, &executable, options)
+}
+
+/// Runs `executable`, the solution of a day built by `build_solutions`, and collects its results
+/// and output.
+pub fn run_executable(
+    year: u16,
+    day: u8,
+    executable: &Path,
+    options: &RunOptions,
+) -> Result<DayRun> {
+    let suffix = format!("{}_{:02}", year, day);
+
+    // results are written to a per-process file, so concurrent runs do not clobber each other.
+    let results_path =
+        env::temp_dir().join(format!("aoc_results_{}_{}.jsonl", suffix, process::id()));
+    #[allow(unused_must_use)]
+    {
+        fs::remove_file(&results_path);
+    }
+
+    let mut cmd = process::Command::new(executable);
+    cmd.env(RESULTS_FILE_ENV, &results_path)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    if let Some(input) = &options.input {
+        cmd.args(["--input", input]);
+    }
+    if let Some(params) = &options.params {
+        cmd.env(PARAMS_ENV, toml::to_string(params)?);
+    }
+    let mut timeout = options.timeout;
+    if let Some(bench) = &options.bench {
+        cmd.env(BENCH_ENV, bench.to_env());
+        // each part runs at most one run past its budget, which `timeout` already allows for.
+        timeout += bench.budget * 2;
+    }
+
+    let mut child = cmd
+        .spawn()
+        .with_context(|| format!("could not run {:?}", executable))?;
+    let stdout = read_to_end(child.stdout.take().unwrap());
+    let stderr = read_to_end(child.stderr.take().unwrap());
+    let exit_status = wait_timeout(&mut child, timeout)?;
+
+    let results = results::read_results(&results_path);
+    #[allow(unused_must_use)]
+    {
+        fs::remove_file(&results_path);
+    }
+
+    let status = match exit_status {
+        None => DayStatus::TimedOut(timeout),
+        Some(exit_status) if !exit_status.success() => DayStatus::Crashed,
+        Some(_) => DayStatus::from_results(&results),
+    };
+
+    Ok(DayRun {
+        status,
+        results,
+        stdout: stdout.join().unwrap(),
+        stderr: stderr.join().unwrap(),
+    })
+}
+
+/// Prints the day header and the output of a solution run by `run_solution`.
+pub fn print_day_run(day: u8, run: &DayRun) {
+    print_day_header(day);
+    if !run.stdout.trim().is_empty() {
+        println!("{}", run.stdout.trim());
+    }
+    print_day_status(run);
+}
+
+/// Prints why a day did not complete, along with what the solution wrote to stderr.
+pub fn print_day_status(run: &DayRun) {
+    match run.status {
+        DayStatus::Solved => return,
+        DayStatus::NotSolved => {
+            if run.results.is_empty() {
+                println!("Not solved.");
+            }
+            return;
+        }
+        DayStatus::Crashed => println!("{}💥 Crashed.{}", ANSI_BOLD, ANSI_RESET),
+        DayStatus::TimedOut(timeout) => {
+            println!(
+                "{}⏱️  Timed out after {:?}.{}",
+                ANSI_BOLD, timeout, ANSI_RESET
+            )
+        }
+    }
+    for line in run.stderr.trim().lines() {
+        println!("  {}", line);
+    }
 }
 
 #[cfg(test)]
@@ -145,30 +463,11 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_parse_exec_time() {
-        assert_approx_eq!(
-            parse_exec_time(&format!(
-                "🎄 Part 1 🎄\n0 (elapsed: 74.13ns){}\n🎄 Part 2 🎄\n0 (elapsed: 50.00ns){}",
-                ANSI_RESET, ANSI_RESET
-            )),
-            0_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 755µs)\n🎄 Part 2 🎄\n0 (elapsed: 700µs)"),
-            1.455_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 70µs)\n🎄 Part 2 🎄\n0 (elapsed: 1.45ms)"),
-            1.52_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time(
-                "🎄 Part 1 🎄\n0 (elapsed: 10.3s)\n🎄 Part 2 🎄\n0 (elapsed: 100.50ms)"
-            ),
-            10400.50_f64
-        );
+    fn test_parse_days() {
+        assert_eq!(parse_days("3"), Ok(vec![3]));
+        assert_eq!(parse_days("10-13"), Ok(vec![10, 11, 12, 13]));
+        assert_eq!(parse_days("1,3,5-7"), Ok(vec![1, 3, 5, 6, 7]));
+        assert!(parse_days("7-5").is_err());
+        assert!(parse_days("x").is_err());
     }
 }
@@ -2,22 +2,200 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::{run_solution, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use advent_of_code::answers::{Answers, Check, Verdict};
+use advent_of_code::aoc::{Client, SubmitOutcome};
+use advent_of_code::bench::BenchConfig;
+use advent_of_code::examples;
+use advent_of_code::history;
+use advent_of_code::{
+    print_day_run, run_solution, Args, RunOptions, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+    DEFAULT_TIMEOUT,
+};
 use std::process;
+use std::time::Duration;
+
+fn submit(year: u16, day: u8, part: u8, answer: &str) {
+    let check = match Answers::load(year, day) {
+        Ok(answers) => answers.check(part, answer),
+        Err(e) => {
+            eprintln!("Failed to load recorded answers: {:#}", e);
+            process::exit(1);
+        }
+    };
+
+    match check {
+        Check::Confirmed => {
+            println!("Answer {} for part {} is already confirmed.", answer, part);
+            return;
+        }
+        Check::Rejected(_) | Check::OutOfBounds { .. } => {
+            eprintln!(
+                "Not submitting: answer {} {}.",
+                answer,
+                check.warning().unwrap()
+            );
+            process::exit(1);
+        }
+        Check::Unverified | Check::Changed { .. } => {}
+    }
+
+    let client = match Client::from_env() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("Failed to submit answer: {}", e);
+            process::exit(1);
+        }
+    };
+
+    println!("Submitting answer {} for part {}...", answer, part);
+
+    let outcome = match client.submit(year, day, part, answer) {
+        Ok(outcome) => outcome,
+        Err(e) => {
+            eprintln!("Failed to submit answer: {:#}", e);
+            process::exit(1);
+        }
+    };
+
+    match &outcome {
+        SubmitOutcome::Correct => println!("⭐ That's the right answer!"),
+        SubmitOutcome::TooHigh => println!("❌ That's not the right answer: too high."),
+        SubmitOutcome::TooLow => println!("❌ That's not the right answer: too low."),
+        SubmitOutcome::Incorrect => println!("❌ That's not the right answer."),
+        SubmitOutcome::RateLimited(Some(wait)) => {
+            println!(
+                "⏳ Answered too recently. Wait {:?} before trying again.",
+                wait
+            )
+        }
+        SubmitOutcome::RateLimited(None) => println!("⏳ Answered too recently."),
+        SubmitOutcome::AlreadyCompleted => println!("This part is already completed."),
+        SubmitOutcome::Unknown(body) => println!("Unexpected response:\n{}", body),
+    }
+
+    if let Some(verdict) = Verdict::from_outcome(&outcome) {
+        let recorded = Answers::load(year, day).and_then(|mut answers| {
+            answers.record(part, answer, verdict);
+            answers.save(year, day)
+        });
+        if let Err(e) = recorded {
+            eprintln!("Failed to record submission: {:#}", e);
+        }
+    }
+}
+
+struct Options {
+    submit: Option<u8>,
+    example: Option<String>,
+    run: RunOptions,
+}
+
+fn parse_args() -> Result<(Args, Options), pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let default = BenchConfig::default();
+    let bench = args.contains("--bench");
+    let runs = args.opt_value_from_str("--bench-runs")?;
+    let budget = args.opt_value_from_str("--bench-time")?;
+    let options = Options {
+        submit: args.opt_value_from_str("--submit")?,
+        example: args.opt_value_from_str("--example")?,
+        run: RunOptions {
+            bench: (bench || runs.is_some() || budget.is_some()).then(|| BenchConfig {
+                runs: runs.unwrap_or(default.runs),
+                budget: budget.map_or(default.budget, Duration::from_millis),
+            }),
+            timeout: args
+                .opt_value_from_str("--timeout")?
+                .map_or(DEFAULT_TIMEOUT, Duration::from_secs),
+            input: args.opt_value_from_str("--input")?,
+            params: None,
+        },
+    };
+    Ok((advent_of_code::parse_day_args(&mut args)?, options))
+}
+
+/// Points `options` at the input and parameters of the example called `name`.
+fn use_example(year: u16, day: u8, name: &str, options: &mut RunOptions) {
+    if options.input.is_some() {
+        eprintln!("Failed to process arguments: --example and --input cannot be combined");
+        process::exit(1);
+    }
+    let example = match examples::load(year, day) {
+        Ok(examples) => examples.into_iter().find(|e| e.name == name),
+        Err(e) => {
+            eprintln!("Failed to load examples: {:#}", e);
+            process::exit(1);
+        }
+    };
+    match example {
+        Some(example) => {
+            let path = examples::example_path(year, day, name);
+            options.input = Some(path.to_string_lossy().into_owned());
+            options.params = Some(example.spec.params);
+        }
+        None => {
+            eprintln!("Failed to find example {:?}.", name);
+            process::exit(1);
+        }
+    }
+}
 
 fn main() {
-    let args = match advent_of_code::parse_args() {
-        Ok(day) => day,
+    let (args, mut options) = match parse_args() {
+        Ok(args) => args,
         Err(e) => {
             eprintln!("Failed to process arguments: {}", e);
             process::exit(1);
         }
     };
 
-    let total = run_solution(args.year, args.day);
+    if let Some(name) = &options.example {
+        use_example(args.year, args.day, name, &mut options.run);
+    }
+    // answers for other inputs neither belong in the ledger nor in the benchmark history.
+    let custom_input = options.run.input.is_some();
+
+    let run = match run_solution(args.year, args.day, &options.run) {
+        Ok(run) => run,
+        Err(e) => {
+            eprintln!("Failed to run solution: {:#}", e);
+            process::exit(1);
+        }
+    };
+    print_day_run(args.day, &run);
+
+    let results = run.results;
+    if options.run.bench.is_some() && !custom_input {
+        if let Err(e) = history::record(args.year, args.day, &results) {
+            eprintln!("Failed to record benchmark history: {:#}", e);
+        }
+    }
+    let total = results.iter().fold(0_f64, |total, r| total + r.millis());
 
     println!(
         "{}Total:{} {}{:.2}ms{}",
         ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, total, ANSI_RESET
     );
+
+    if let Some(part) = options.submit {
+        if custom_input {
+            eprintln!(
+                "Not submitting: answers for --input or --example are not your puzzle answers."
+            );
+            process::exit(1);
+        }
+        match results.iter().find(|r| r.part == part) {
+            Some(result) if result.solved => {
+                submit(args.year, args.day, part, result.answer.as_ref().unwrap())
+            }
+            _ => {
+                eprintln!("Part {} has no answer to submit.", part);
+                process::exit(1);
+            }
+        }
+    }
+
+    if run.status.is_failure() {
+        process::exit(1);
+    }
 }
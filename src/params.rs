@@ -0,0 +1,80 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use serde::de::DeserializeOwned;
+use std::cell::RefCell;
+use std::env;
+
+/// Puzzle parameters passed to a solution binary, as a TOML table. See `params`.
+pub const PARAMS_ENV: &str = "AOC_PARAMS";
+
+pub type Table = toml::value::Table;
+
+thread_local! {
+    static OVERRIDE: RefCell<Option<Table>> = const { RefCell::new(None) };
+}
+
+/// Parameters of the puzzle a solution is running on, for values that differ between
+/// the examples and the real input, such as the row to scan.
+///
+/// `T` defaults to the values of the real input and is overridden by the `params` table of
+/// an example (see `examples`), or `AOC_PARAMS` when run as a binary. Mark `T` with
+/// `#[serde(default)]` so examples only need to declare the values that differ.
+pub fn params<T: DeserializeOwned + Default>() -> T {
+    let table = OVERRIDE.with(|o| o.borrow().clone()).or_else(|| {
+        let value = env::var(PARAMS_ENV).ok()?;
+        Some(toml::from_str(&value).unwrap_or_else(|e| panic!("invalid {}: {}", PARAMS_ENV, e)))
+    });
+    match table {
+        Some(table) => toml::Value::Table(table)
+            .try_into()
+            .unwrap_or_else(|e| panic!("invalid puzzle parameters: {}", e)),
+        None => T::default(),
+    }
+}
+
+/// Runs `func` with `table` as the parameters returned by `params` on this thread.
+pub fn with_params<R>(table: &Table, func: impl FnOnce() -> R) -> R {
+    let previous = OVERRIDE.with(|o| o.replace(Some(table.clone())));
+    let result = func();
+    OVERRIDE.with(|o| *o.borrow_mut() = previous);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    #[serde(default)]
+    struct Params {
+        row: i64,
+        rounds: u32,
+    }
+
+    impl Default for Params {
+        fn default() -> Self {
+            Params {
+                row: 2_000_000,
+                rounds: 20,
+            }
+        }
+    }
+
+    #[test]
+    fn test_params() {
+        assert_eq!(params::<Params>(), Params::default());
+
+        let table: Table = toml::from_str("row = 10").unwrap();
+        assert_eq!(
+            with_params(&table, params::<Params>),
+            Params {
+                row: 10,
+                rounds: 20
+            }
+        );
+        assert_eq!(params::<Params>(), Params::default());
+    }
+}
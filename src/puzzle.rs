@@ -0,0 +1,400 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::examples::{self, example_path, specs_path, DEFAULT_EXAMPLE};
+use crate::{ANSI_BOLD, ANSI_CYAN, ANSI_ITALIC, ANSI_RESET, ANSI_YELLOW};
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::PathBuf;
+
+/// Heading that starts the description of part two.
+pub const PART_TWO_MARKER: &str = "--- Part Two ---";
+/// Text following the description of a solved part.
+const ANSWER_MARKER: &str = "Your puzzle answer was";
+
+/// Ways an emphasized code span is written: as markdown (by `aoc-cli` and `cargo download`) or as HTML.
+const EMPHASIZED_CODE: [(&str, &str); 4] = [
+    ("`*", "*`"),
+    ("*`", "`*"),
+    ("<code><em>", "</em></code>"),
+    ("<em><code>", "</code></em>"),
+];
+
+pub fn puzzle_path(year: u16, day: u8) -> PathBuf {
+    crate::data_dir()
+        .join("puzzles")
+        .join(format!("{}_{:02}.md", year, day))
+}
+
+/// What can be pulled out of a puzzle description for the tests of a day.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Extracted {
+    /// Contents of the first code block, which usually is the example input.
+    pub example: Option<String>,
+    /// Answer of each part for the example. Part two is `None` until it is unlocked.
+    pub answers: [Option<String>; 2],
+}
+
+fn decode_entities(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Converts the puzzle page of the website into the markdown `aoc-cli` writes: the text of the
+/// `<article>`s with the answers given so far, `<code><em>` as `` `*` ``, `<pre>` as fenced code.
+pub fn html_to_markdown(html: &str) -> String {
+    let mut out = String::new();
+    let mut in_pre = false;
+    let mut link: Option<String> = None;
+    let mut rest = html;
+
+    while let Some(start) = rest.find('<') {
+        let text = &rest[..start];
+        match in_pre {
+            true => out.push_str(&decode_entities(text)),
+            false => out.push_str(&decode_entities(&text.replace('\n', " "))),
+        }
+        let end = match rest[start..].find('>') {
+            Some(end) => start + end,
+            None => break,
+        };
+        let tag = &rest[start + 1..end];
+        rest = &rest[end + 1..];
+
+        let (closing, tag) = match tag.strip_prefix('/') {
+            Some(tag) => (true, tag),
+            None => (false, tag),
+        };
+        let (name, attributes) = tag.split_once(' ').unwrap_or((tag, ""));
+        match (name, closing) {
+            ("pre", false) => {
+                in_pre = true;
+                out.push_str("```\n");
+            }
+            ("pre", true) => {
+                in_pre = false;
+                if !out.ends_with('\n') {
+                    out.push('\n');
+                }
+                out.push_str("```\n\n");
+            }
+            _ if in_pre => {}
+            ("h2", false) => out.push_str("## "),
+            ("h2" | "p", true) | ("ul", true) => out.push_str("\n\n"),
+            ("li", false) => out.push_str("\n- "),
+            ("code", _) => out.push('`'),
+            ("em", _) => out.push('*'),
+            ("a", false) => {
+                link = attributes
+                    .split_once("href=\"")
+                    .and_then(|(_, href)| Some(href.split_once('"')?.0.to_string()));
+                out.push('[');
+            }
+            ("a", true) => {
+                out.push(']');
+                if let Some(href) = link.take() {
+                    out.push_str(&format!("({})", href));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // tidy up the whitespace around block elements, except for the contents of code blocks.
+    let mut markdown = String::new();
+    let mut in_code = false;
+    for line in out.lines() {
+        let line = match line.trim() {
+            "```" => {
+                in_code = !in_code;
+                "```"
+            }
+            _ if in_code => line,
+            trimmed => trimmed,
+        };
+        if !in_code && line.is_empty() && (markdown.is_empty() || markdown.ends_with("\n\n")) {
+            continue;
+        }
+        markdown.push_str(line);
+        markdown.push('\n');
+    }
+    markdown.trim_end().to_string() + "\n"
+}
+
+/// The part of a puzzle page that `html_to_markdown` converts: the description of each part
+/// and the answer given to it.
+pub fn puzzle_html(page: &str) -> String {
+    let mut html = String::new();
+    let mut rest = page;
+    loop {
+        let article = rest.find("<article");
+        let answer = rest.find("<p>Your puzzle answer was");
+        let (start, end_tag) = match (article, answer) {
+            (Some(a), Some(b)) if b < a => (b, "</p>"),
+            (Some(a), _) => (a, "</article>"),
+            (None, Some(b)) => (b, "</p>"),
+            (None, None) => break,
+        };
+        let end = match rest[start..].find(end_tag) {
+            Some(end) => start + end + end_tag.len(),
+            None => break,
+        };
+        html.push_str(&rest[start..end]);
+        html.push('\n');
+        rest = &rest[end..];
+    }
+    html
+}
+
+/// The first code block, either fenced markdown or a `<pre><code>` element.
+fn first_code_block(markdown: &str) -> Option<String> {
+    let fenced = markdown.find("```").map(|start| {
+        let body = &markdown[start..];
+        let body = &body[body.find('\n').map_or(body.len(), |i| i + 1)..];
+        let end = body.find("```").unwrap_or(body.len());
+        (start, body[..end].to_string())
+    });
+    let html = markdown.find("<pre><code>").map(|start| {
+        let body = &markdown[start + "<pre><code>".len()..];
+        let end = body.find("</code></pre>").unwrap_or(body.len());
+        (start, decode_entities(&body[..end]))
+    });
+
+    let (_, block) = match (fenced, html) {
+        (Some(fenced), Some(html)) => std::cmp::min_by_key(fenced, html, |(start, _)| *start),
+        (block, None) | (None, block) => block?,
+    };
+    Some(block.trim_end_matches('\n').to_string())
+}
+
+/// The last emphasized code span of `section`, which is where puzzles state the example's answer.
+fn last_emphasized_code(section: &str) -> Option<String> {
+    EMPHASIZED_CODE
+        .iter()
+        .flat_map(|(open, close)| {
+            section.match_indices(open).filter_map(move |(start, _)| {
+                let value = &section[start + open.len()..];
+                let value = &value[..value.find(close)?];
+                let valid = !value.is_empty()
+                    && !value.contains(|c: char| c.is_whitespace() || "`*<>".contains(c));
+                valid.then(|| (start, decode_entities(value)))
+            })
+        })
+        .max_by_key(|(start, _)| *start)
+        .map(|(_, value)| value)
+}
+
+/// Splits a puzzle description into the description of part one and, once unlocked, part two.
+pub fn split_parts(markdown: &str) -> (&str, Option<&str>) {
+    match markdown.find(PART_TWO_MARKER) {
+        Some(index) => {
+            // split at the start of the heading line, which may be prefixed by `## ` or `\`.
+            let index = markdown[..index].rfind('\n').map_or(0, |i| i + 1);
+            (&markdown[..index], Some(&markdown[index..]))
+        }
+        None => (markdown, None),
+    }
+}
+
+pub fn extract(markdown: &str) -> Extracted {
+    let (part_one, part_two) = split_parts(markdown);
+    let answer = |section: &str| {
+        let description = section.split(ANSWER_MARKER).next().unwrap_or(section);
+        last_emphasized_code(description)
+    };
+
+    Extracted {
+        example: first_code_block(part_one),
+        answers: [answer(part_one), part_two.and_then(answer)],
+    }
+}
+
+/// Styles the inline markdown of a line: emphasized code such as the answers in bold yellow,
+/// code in cyan, emphasis in italics and links as their text.
+fn render_inline(line: &str) -> String {
+    let mut out = String::new();
+    let mut italic = false;
+    let mut rest = line;
+
+    while let Some(c) = rest.chars().next() {
+        let emphasized_code = EMPHASIZED_CODE[..2].iter().find_map(|(open, close)| {
+            let value = rest.strip_prefix(open)?;
+            let end = value.find(close)?;
+            Some((&value[..end], open.len() + end + close.len()))
+        });
+        if let Some((value, len)) = emphasized_code {
+            out.push_str(&format!(
+                "{}{}{}{}",
+                ANSI_BOLD, ANSI_YELLOW, value, ANSI_RESET
+            ));
+            rest = &rest[len..];
+        } else if let Some((code, _)) = rest.strip_prefix('`').and_then(|r| r.split_once('`')) {
+            out.push_str(&format!("{}{}{}", ANSI_CYAN, code, ANSI_RESET));
+            rest = &rest[code.len() + 2..];
+        } else if let Some((text, url)) = rest
+            .strip_prefix('[')
+            .and_then(|r| r.split_once("]("))
+            .and_then(|(text, r)| Some((text, r.split_once(')')?.0)))
+        {
+            out.push_str(&render_inline(text));
+            rest = &rest[text.len() + url.len() + 4..];
+        } else if c == '*' {
+            italic = !italic;
+            out.push_str(if italic { ANSI_ITALIC } else { ANSI_RESET });
+            rest = &rest[1..];
+        } else if c == '\\' && rest[1..].starts_with(|c: char| c.is_ascii_punctuation()) {
+            // markdown escapes, as in `\--- Day 1 ---`.
+            out.push_str(&rest[1..2]);
+            rest = &rest[2..];
+        } else {
+            out.push(c);
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    if italic {
+        out.push_str(ANSI_RESET);
+    }
+    out
+}
+
+/// Renders puzzle markdown for the terminal: headings in bold and code blocks indented in cyan.
+pub fn render(markdown: &str) -> String {
+    let mut out = String::new();
+    let mut in_code = false;
+    for line in markdown.lines() {
+        if line.trim() == "```" {
+            in_code = !in_code;
+            continue;
+        }
+        if in_code {
+            out.push_str(&format!("    {}{}{}\n", ANSI_CYAN, line, ANSI_RESET));
+        } else if !line.is_empty() && line.chars().all(|c| c == '-') {
+            // the underline `aoc-cli` puts below headings.
+            continue;
+        } else if let Some(heading) = line.strip_prefix("## ").or_else(|| {
+            line.starts_with("\\---")
+                .then_some(line)
+                .filter(|l| l.ends_with("---"))
+        }) {
+            out.push_str(&format!(
+                "{}{}{}\n",
+                ANSI_BOLD,
+                render_inline(heading),
+                ANSI_RESET
+            ));
+        } else {
+            out.push_str(&render_inline(line));
+            out.push('\n');
+        }
+    }
+    out
+}
+
+/// What `update_examples` changed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Update {
+    pub example: Option<PathBuf>,
+    pub answers: Vec<(u8, String)>,
+}
+
+/// Fills the default example of a day from its puzzle description: writes the example input if
+/// the example file is still empty, and declares the example answers that are not declared yet.
+/// Run it again once part two is unlocked to add its answer.
+pub fn update_examples(year: u16, day: u8) -> Result<Update> {
+    let path = puzzle_path(year, day);
+    let markdown =
+        fs::read_to_string(&path).with_context(|| format!("could not read {:?}", path))?;
+    let extracted = extract(&markdown);
+    let mut update = Update::default();
+
+    let example_path = example_path(year, day, DEFAULT_EXAMPLE);
+    let current = fs::read_to_string(&example_path).unwrap_or_default();
+    if let Some(example) = extracted.example.filter(|_| current.trim().is_empty()) {
+        crate::write_atomic(&example_path, &example)?;
+        update.example = Some(example_path);
+    }
+
+    let specs_path = specs_path(year, day);
+    let specs = fs::read_to_string(&specs_path).unwrap_or_default();
+    let (specs, declared) = examples::declare_answers(&specs, DEFAULT_EXAMPLE, &extracted.answers)
+        .with_context(|| format!("invalid {:?}", specs_path))?;
+    if !declared.is_empty() {
+        crate::write_atomic(&specs_path, &specs)?;
+    }
+    update.answers = declared;
+    Ok(update)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PUZZLE: &str = "\\--- Day 1: Calorie Counting ---
+----------
+
+For example, suppose the Elves finish writing their items' *Calories* like this:
+
+```
+1000
+2000
+
+3000
+```
+
+In case the Elves get hungry, they need to know which Elf has the most: they'd like to know how many Calories are being carried by the Elf carrying the *most* Calories. In the example above, this is `*24000*` (carried by the fourth Elf).
+
+Your puzzle answer was `69501`.
+
+\\--- Part Two ---
+----------
+
+In the example above, the top three Elves are the fourth Elf (with `24000` Calories), then the third Elf (with `11000` Calories), then the fifth Elf (with `10000` Calories). The sum of the Calories carried by these three elves is `*45000*`.
+";
+
+    #[test]
+    fn test_extract() {
+        assert_eq!(
+            extract(PUZZLE),
+            Extracted {
+                example: Some("1000\n2000\n\n3000".into()),
+                answers: [Some("24000".into()), Some("45000".into())],
+            }
+        );
+
+        let part_one = &PUZZLE[..PUZZLE.find("\\--- Part Two").unwrap()];
+        assert_eq!(extract(part_one).answers, [Some("24000".into()), None]);
+
+        let html = "<pre><code>[D]\n&lt;1&gt;\n</code></pre>\n<p>the top crates are <code><em>CMZ</em></code>.</p>";
+        assert_eq!(
+            extract(html),
+            Extracted {
+                example: Some("[D]\n<1>".into()),
+                answers: [Some("CMZ".into()), None],
+            }
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let (part_one, part_two) = split_parts(PUZZLE);
+        assert!(part_one.ends_with("`69501`.\n\n"));
+        assert!(part_two.unwrap().starts_with("\\--- Part Two ---\n"));
+        assert_eq!(split_parts("## --- Day 1 ---\n").1, None);
+
+        assert_eq!(
+            render("\\--- Day 1 ---\n----------\n\nThe *most* is `*24000*`, see [`x`](/x):\n\n```\n1000\n```\n"),
+            format!(
+                "{b}--- Day 1 ---{r}\n\nThe {i}most{r} is {b}{y}24000{r}, see {c}x{r}:\n\n    {c}1000{r}\n",
+                b = ANSI_BOLD,
+                i = ANSI_ITALIC,
+                c = ANSI_CYAN,
+                y = ANSI_YELLOW,
+                r = ANSI_RESET
+            )
+        );
+    }
+}
@@ -0,0 +1,125 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use itertools::Itertools;
+use std::fmt::Write;
+use std::time::Duration;
+
+/// Marks the start and end of the generated table in the readme.
+pub const TABLE_MARKER: &str = "<!--- advent_readme_stars table --->";
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PartSummary {
+    pub solved: bool,
+    pub nanos: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DaySummary {
+    pub year: u16,
+    pub day: u8,
+    pub parts: [PartSummary; 2],
+}
+
+fn star(part: &PartSummary) -> &'static str {
+    match part.solved {
+        true => "⭐",
+        false => " ",
+    }
+}
+
+fn timing(part: &PartSummary) -> String {
+    match (part.solved, part.nanos) {
+        (true, Some(nanos)) => format!("{:.2?}", Duration::from_nanos(nanos)),
+        _ => "-".to_string(),
+    }
+}
+
+/// Renders one results table per year, newest year first.
+pub fn render_table(days: &[DaySummary]) -> String {
+    let mut table = String::new();
+    let by_year = days
+        .iter()
+        .sorted_by_key(|d| (std::cmp::Reverse(d.year), d.day))
+        .group_by(|d| d.year);
+
+    for (year, days) in by_year.into_iter() {
+        writeln!(table, "## {} Results\n", year).unwrap();
+        writeln!(table, "| Day | Part 1 | Time | Part 2 | Time |").unwrap();
+        writeln!(table, "| :---: | :---: | :---: | :---: | :---: |").unwrap();
+        for d in days {
+            writeln!(
+                table,
+                "| [Day {day}](https://adventofcode.com/{year}/day/{day}) | {} | {} | {} | {} |",
+                star(&d.parts[0]),
+                timing(&d.parts[0]),
+                star(&d.parts[1]),
+                timing(&d.parts[1]),
+                day = d.day,
+                year = d.year,
+            )
+            .unwrap();
+        }
+        table.push('\n');
+    }
+    table.trim_end().to_string()
+}
+
+/// Replaces the contents between the two table markers of `readme` with `table`.
+pub fn replace_table(readme: &str, table: &str) -> Option<String> {
+    let (before, rest) = readme.split_once(TABLE_MARKER)?;
+    let (_, after) = rest.split_once(TABLE_MARKER)?;
+    Some(format!(
+        "{}{}\n{}\n{}{}",
+        before, TABLE_MARKER, table, TABLE_MARKER, after
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_replace_table() {
+        let days = vec![
+            DaySummary {
+                year: 2022,
+                day: 2,
+                parts: [
+                    PartSummary {
+                        solved: true,
+                        nanos: Some(1_500),
+                    },
+                    PartSummary::default(),
+                ],
+            },
+            DaySummary {
+                year: 2022,
+                day: 1,
+                parts: [PartSummary {
+                    solved: true,
+                    nanos: None,
+                }; 2],
+            },
+        ];
+        let readme = format!(
+            "# Title\n\n{}\n## 2022 Results\n| stale |\n{}\n\n## Usage\n",
+            TABLE_MARKER, TABLE_MARKER
+        );
+
+        assert_eq!(
+            replace_table(&readme, &render_table(&days)).unwrap(),
+            format!(
+                "# Title\n\n{m}\n## 2022 Results\n\n\
+                | Day | Part 1 | Time | Part 2 | Time |\n\
+                | :---: | :---: | :---: | :---: | :---: |\n\
+                | [Day 1](https://adventofcode.com/2022/day/1) | ⭐ | - | ⭐ | - |\n\
+                | [Day 2](https://adventofcode.com/2022/day/2) | ⭐ | 1.50µs |   | - |\n\
+                {m}\n\n## Usage\n",
+                m = TABLE_MARKER
+            )
+        );
+        assert_eq!(replace_table("no markers", ""), None);
+    }
+}
@@ -0,0 +1,142 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ *
+ * Runners call solutions in-process instead of spawning `cargo run` per day. `build.rs` generates
+ * the list of every `src/bin/YYYY_DD.rs`, which a runner compiles in with `solutions!()`, so an
+ * unfinished day only breaks the runners and not the library or the other binaries.
+ */
+use crate::bench::measure;
+use crate::results::{Answer, DayRun, DayStatus, PartResult};
+use crate::{check_answer, print_part_header, print_part_result};
+use std::cell::{Cell, RefCell};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Once;
+use std::thread;
+use std::time::{Duration, Instant};
+
+thread_local! {
+    static CAPTURE_PANICS: Cell<bool> = const { Cell::new(false) };
+    static PANIC_MESSAGE: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Wraps the panic hook so panics of solution threads are recorded instead of printed.
+fn capture_panics() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| match CAPTURE_PANICS.with(Cell::get) {
+            true => PANIC_MESSAGE.with(|m| *m.borrow_mut() = Some(info.to_string())),
+            false => default_hook(info),
+        }));
+    });
+}
+
+pub trait Solution: Sync {
+    fn part_one(&self, input: &str) -> Answer;
+    fn part_two(&self, input: &str) -> Answer;
+}
+
+/// A `Solution` backed by a day's `part_one` and `part_two` functions.
+pub struct Parts(pub fn(&str) -> Answer, pub fn(&str) -> Answer);
+
+impl Solution for Parts {
+    fn part_one(&self, input: &str) -> Answer {
+        (self.0)(input)
+    }
+
+    fn part_two(&self, input: &str) -> Answer {
+        (self.1)(input)
+    }
+}
+
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    pub solution: &'static dyn Solution,
+}
+
+impl Entry {
+    /// Runs a single part against `input` and times it, see `bench::measure`.
+    pub fn solve(&self, part: u8, input: &str) -> PartResult {
+        match part {
+            1 => measure(part, |i| self.solution.part_one(i), input),
+            _ => measure(part, |i| self.solution.part_two(i), input),
+        }
+    }
+
+    /// Runs both parts against `input`.
+    pub fn run(&self, input: &str) -> Vec<PartResult> {
+        [1, 2]
+            .into_iter()
+            .map(|part| self.solve(part, input))
+            .collect()
+    }
+
+    /// Runs both parts on a separate thread, stopping at the first panic or once `timeout` is up.
+    /// A timed out solution cannot be stopped and keeps running in the background.
+    pub fn run_guarded(&'static self, input: String, timeout: Duration) -> DayRun {
+        capture_panics();
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            CAPTURE_PANICS.with(|c| c.set(true));
+            for part in [1, 2] {
+                let result = panic::catch_unwind(AssertUnwindSafe(|| self.solve(part, &input)))
+                    .map_err(|_| PANIC_MESSAGE.with(|m| m.take()).unwrap_or_default());
+                let crashed = result.is_err();
+                if tx.send(result).is_err() || crashed {
+                    break;
+                }
+            }
+        });
+
+        let deadline = Instant::now() + timeout;
+        let mut run = DayRun {
+            status: DayStatus::NotSolved,
+            results: vec![],
+            stdout: String::new(),
+            stderr: String::new(),
+        };
+        loop {
+            match rx.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+                Ok(Ok(result)) => run.results.push(result),
+                Ok(Err(message)) => {
+                    run.status = DayStatus::Crashed;
+                    run.stderr = message;
+                    return run;
+                }
+                Err(RecvTimeoutError::Timeout) => {
+                    run.status = DayStatus::TimedOut(timeout);
+                    return run;
+                }
+                Err(RecvTimeoutError::Disconnected) => break,
+            }
+        }
+        run.status = DayStatus::from_results(&run.results);
+        run
+    }
+
+    /// Prints results of `run` the same way `solve!` does.
+    pub fn print_results(&self, results: &[PartResult]) {
+        for result in results {
+            print_part_header(result.part);
+            print_part_result(Some((self.year, self.day)), result);
+            check_answer(self.year, self.day, result);
+        }
+    }
+}
+
+/// Declares `SOLUTIONS`, an `Entry` for every `src/bin/YYYY_DD.rs` sorted by year and day,
+/// by compiling the solutions into the calling binary.
+#[macro_export]
+macro_rules! solutions {
+    () => {
+        // solutions carry their own unit tests, which already run as part of their binaries.
+        #[cfg(not(test))]
+        include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+
+        #[cfg(test)]
+        static SOLUTIONS: &[$crate::registry::Entry] = &[];
+    };
+}
@@ -0,0 +1,220 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::bench::BenchStats;
+use serde::{Deserialize, Serialize};
+use std::env;
+use std::fmt::Display;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::time::Duration;
+
+/// Environment variable naming the file `solve!` appends its results to.
+/// `run_solution` sets it for the solution process, so results can be read back
+/// without parsing the (colored, possibly noisy) stdout of a solution.
+pub const RESULTS_FILE_ENV: &str = "AOC_RESULTS_FILE";
+
+/// What a part returned, see `IntoAnswer`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Solved(String),
+    NotSolved,
+    Failed(String),
+}
+
+/// Return types `solve!` accepts for `part_one` and `part_two`.
+///
+/// `Option<T>` is solved for `Some`; `Result<T, E>` is solved for `Ok` and failed with the
+/// error message for `Err`, so solutions can use `?` instead of unwrapping while parsing.
+pub trait IntoAnswer {
+    fn into_answer(self) -> Answer;
+}
+
+impl IntoAnswer for Answer {
+    fn into_answer(self) -> Answer {
+        self
+    }
+}
+
+impl<T: Display> IntoAnswer for Option<T> {
+    fn into_answer(self) -> Answer {
+        match self {
+            Some(answer) => Answer::Solved(answer.to_string()),
+            None => Answer::NotSolved,
+        }
+    }
+}
+
+impl<T: Display, E: Display> IntoAnswer for Result<T, E> {
+    fn into_answer(self) -> Answer {
+        match self {
+            Ok(answer) => Answer::Solved(answer.to_string()),
+            // `{:#}` includes the causes of `anyhow` errors.
+            Err(e) => Answer::Failed(format!("{:#}", e)),
+        }
+    }
+}
+
+/// Machine-readable result of running a single part, stored as one JSON line.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PartResult {
+    pub part: u8,
+    pub answer: Option<String>,
+    pub nanos: u64,
+    pub solved: bool,
+    /// Set when the part returned an error.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Set when the part was benchmarked; `nanos` then holds the median.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bench: Option<BenchStats>,
+}
+
+impl PartResult {
+    pub fn new(part: u8, answer: Answer, nanos: u64) -> Self {
+        let (answer, error) = match answer {
+            Answer::Solved(answer) => (Some(answer), None),
+            Answer::NotSolved => (None, None),
+            Answer::Failed(error) => (None, Some(error)),
+        };
+        PartResult {
+            part,
+            solved: answer.is_some(),
+            answer,
+            error,
+            nanos,
+            bench: None,
+        }
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        Duration::from_nanos(self.nanos)
+    }
+
+    pub fn millis(&self) -> f64 {
+        self.nanos as f64 / 1_000_000_f64
+    }
+}
+
+/// Outcome of running all parts of a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DayStatus {
+    Solved,
+    NotSolved,
+    /// The solution panicked or exited with an error.
+    Crashed,
+    TimedOut(Duration),
+}
+
+impl DayStatus {
+    /// Status of a day that ran to completion: solved if any part produced an answer.
+    pub fn from_results(results: &[PartResult]) -> Self {
+        match results.iter().any(|r| r.solved) {
+            true => DayStatus::Solved,
+            false => DayStatus::NotSolved,
+        }
+    }
+
+    pub fn is_failure(&self) -> bool {
+        matches!(self, DayStatus::Crashed | DayStatus::TimedOut(_))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DayRun {
+    pub status: DayStatus,
+    /// Results of the parts that finished, even if a later part crashed or timed out.
+    pub results: Vec<PartResult>,
+    /// Output of the solution, when run as a separate process.
+    pub stdout: String,
+    pub stderr: String,
+}
+
+/// Appends `result` to the results file, if one was requested via `AOC_RESULTS_FILE`.
+pub fn emit(result: &PartResult) {
+    let path = match env::var_os(RESULTS_FILE_ENV) {
+        Some(path) => path,
+        None => return,
+    };
+
+    let line = serde_json::to_string(result).expect("could not serialize result");
+
+    let written = OpenOptions::new()
+        .append(true)
+        .create(true)
+        .open(&path)
+        .and_then(|mut file| writeln!(file, "{}", line));
+
+    if let Err(e) = written {
+        eprintln!("could not write result to {:?}: {}", path, e);
+    }
+}
+
+/// Parses JSON lines as written by `emit`. Lines that are not valid records are skipped.
+pub fn parse_results(contents: &str) -> Vec<PartResult> {
+    contents
+        .lines()
+        .filter_map(|l| serde_json::from_str(l).ok())
+        .collect()
+}
+
+pub fn read_results(path: &Path) -> Vec<PartResult> {
+    match fs::read_to_string(path) {
+        Ok(contents) => parse_results(&contents),
+        Err(_) => vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_results() {
+        let solved = PartResult {
+            part: 1,
+            answer: Some("CMZ".into()),
+            nanos: 74_130,
+            solved: true,
+            error: None,
+            bench: None,
+        };
+        let unsolved = PartResult {
+            part: 2,
+            answer: None,
+            nanos: 50,
+            solved: false,
+            error: Some("invalid input".into()),
+            bench: Some(BenchStats::from_samples(&[40, 50, 60])),
+        };
+
+        let contents = format!(
+            "{}\nnot a record\n{}\n",
+            serde_json::to_string(&solved).unwrap(),
+            serde_json::to_string(&unsolved).unwrap()
+        );
+
+        let results = parse_results(&contents);
+        assert_eq!(results, vec![solved, unsolved]);
+        assert_eq!(DayStatus::from_results(&results), DayStatus::Solved);
+        assert_eq!(DayStatus::from_results(&results[1..]), DayStatus::NotSolved);
+    }
+
+    #[test]
+    fn test_into_answer() {
+        assert_eq!(Some(42).into_answer(), Answer::Solved("42".into()));
+        assert_eq!(None::<u32>.into_answer(), Answer::NotSolved);
+        assert_eq!(
+            Ok::<_, String>("CMZ").into_answer(),
+            Answer::Solved("CMZ".into())
+        );
+
+        let error = anyhow::anyhow!("expected a number").context("invalid line 3");
+        assert_eq!(
+            Err::<u32, _>(error).into_answer(),
+            Answer::Failed("invalid line 3: expected a number".into())
+        );
+    }
+}
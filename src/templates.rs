@@ -0,0 +1,104 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::PathBuf;
+
+/// Template `cargo scaffold` uses for new solutions unless `--template` is given.
+pub const DEFAULT_TEMPLATE: &str = "default";
+
+pub fn templates_dir() -> PathBuf {
+    crate::project_root().join(".aoc").join("templates")
+}
+
+/// Names of the solution templates in `.aoc/templates`, i.e. the `<name>.rs.tmpl` files.
+pub fn available() -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(templates_dir())
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let file_name = entry.ok()?.file_name();
+            Some(file_name.to_str()?.strip_suffix(".rs.tmpl")?.to_string())
+        })
+        .collect();
+    names.sort();
+    names
+}
+
+/// Loads `.aoc/templates/<file_name>`.
+pub fn load(file_name: &str) -> Result<String> {
+    let path = templates_dir().join(file_name);
+    fs::read_to_string(&path).with_context(|| format!("could not read template {:?}", path))
+}
+
+/// Loads the solution template called `name`.
+pub fn load_solution(name: &str) -> Result<String> {
+    load(&format!("{}.rs.tmpl", name)).with_context(|| {
+        format!(
+            "no template called \"{}\", available: {}",
+            name,
+            available().join(", ")
+        )
+    })
+}
+
+/// Placeholders available to templates of a day.
+pub fn day_vars(year: u16, day: u8) -> Vec<(&'static str, String)> {
+    vec![
+        ("year", year.to_string()),
+        ("day", day.to_string()),
+        ("day_padded", format!("{:02}", day)),
+    ]
+}
+
+/// Replaces every `{{name}}` in `template` with its value in `vars`. Any other `{{`, such as
+/// in `format!("{{}}")`, is left as it is.
+pub fn render(template: &str, vars: &[(&str, String)]) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let value = rest.find("}}").and_then(|end| {
+            let name = rest[2..end].trim();
+            let (_, value) = vars.iter().find(|(n, _)| *n == name)?;
+            Some((value, end))
+        });
+        match value {
+            Some((value, end)) => {
+                out.push_str(value);
+                rest = &rest[end + 2..];
+            }
+            None => {
+                out.push_str("{{");
+                rest = &rest[2..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let vars = day_vars(2022, 9);
+        assert_eq!(
+            render(
+                "read_input({{year}}, {{ day }}); // {{year}}_{{day_padded}}.txt, TODAY",
+                &vars
+            ),
+            "read_input(2022, 9); // 2022_09.txt, TODAY"
+        );
+        assert_eq!(
+            render("format!(\"{{}} {{{{ {{month}}\"); {{year", &vars),
+            "format!(\"{{}} {{{{ {{month}}\"); {{year"
+        );
+        assert_eq!(render("{{{{year}}}}", &vars), "{{2022}}");
+    }
+}